@@ -0,0 +1,122 @@
+use crate::ast::entries::type_name::TypeName;
+use crate::ast::xml::escape;
+
+/// GtkExpression
+///
+/// `expr item as <Gtk.StringObject>.string`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expression {
+    /// Item the expression is evaluated for, `item`
+    Item,
+
+    /// Named object, `my_object`
+    Object(String),
+
+    /// Literal value, `"text"`, `12`, `true`
+    Constant {
+        type_name: String,
+        value: String
+    },
+
+    /// Property lookup, `source.name` or `source as <Type>.name`
+    Lookup {
+        name: String,
+        type_name: Option<String>,
+        source: Option<Box<Expression>>
+    },
+
+    /// Closure call, `$function(args) as <Type>`
+    Closure {
+        function: String,
+        type_name: String,
+        args: Vec<Expression>
+    }
+}
+
+impl Expression {
+    /// Get pretty string description of this expression
+    pub fn dbg(&self) -> String {
+        match self {
+            Self::Item => String::from("item"),
+            Self::Object(name) => name.clone(),
+            Self::Constant { type_name, value } => format!("{}({:?})", type_name, value),
+
+            Self::Lookup { name, type_name, source } => {
+                let source = match source {
+                    Some(source) => source.dbg(),
+                    None => String::from("item")
+                };
+
+                match type_name {
+                    Some(type_name) => format!("{} as <{}>.{}", source, type_name, name),
+                    None => format!("{}.{}", source, name)
+                }
+            }
+
+            Self::Closure { function, type_name, args } => format!(
+                "${}({}) as <{}>",
+                function,
+                args.iter().map(|arg| arg.dbg()).collect::<Vec<String>>().join(", "),
+                type_name
+            )
+        }
+    }
+
+    /// Get XML description of this expression
+    pub fn get_xml(&self) -> String {
+        match self {
+            Self::Item => String::new(),
+            Self::Object(name) => format!("<constant>{}</constant>", name),
+
+            Self::Constant { type_name, value } => {
+                format!("<constant type=\"{}\">{}</constant>", get_gtype_name(type_name), escape(value))
+            }
+
+            Self::Lookup { name, type_name, source } => {
+                let type_name = match type_name {
+                    Some(type_name) => format!(" type=\"{}\"", get_gtype_name(type_name)),
+                    None => String::new()
+                };
+
+                match source {
+                    Some(source) => format!("<lookup name=\"{}\"{}>{}</lookup>", name, type_name, source.get_xml()),
+                    None => format!("<lookup name=\"{}\"{}/>", name, type_name)
+                }
+            }
+
+            Self::Closure { function, type_name, args } => format!(
+                "<closure type=\"{}\" function=\"{}\">{}</closure>",
+                get_gtype_name(type_name),
+                function,
+                args.iter().map(|arg| arg.get_xml()).collect::<String>()
+            )
+        }
+    }
+}
+
+/// Get GType name of the blueprint type
+///
+/// ```
+/// use gtk_ui_builder::ast::entries::expression::get_gtype_name;
+///
+/// assert_eq!(get_gtype_name("string"), "gchararray");
+/// assert_eq!(get_gtype_name("Gtk.StringObject"), "GtkStringObject");
+/// assert_eq!(get_gtype_name("$MyItem"), "MyItem");
+/// ```
+pub fn get_gtype_name(type_name: &str) -> String {
+    match type_name {
+        "bool"   => String::from("gboolean"),
+        "int"    => String::from("gint"),
+        "uint"   => String::from("guint"),
+        "int64"  => String::from("gint64"),
+        "uint64" => String::from("guint64"),
+        "float"  => String::from("gfloat"),
+        "double" => String::from("gdouble"),
+        "string" => String::from("gchararray"),
+
+        _ => match TypeName::parse(type_name) {
+            Some(type_name) => type_name.get_gtype_name(),
+            None => type_name.replace('.', "")
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::ast::entry::Entry;
+use crate::ast::entries::property::PropertyValue;
+use crate::ast::xml::escape;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MenuKind {
+    Menu,
+    Section,
+    Submenu,
+    Item
+}
+
+impl MenuKind {
+    pub fn parse(keyword: &str) -> Option<Self> {
+        match keyword {
+            "menu"    => Some(Self::Menu),
+            "section" => Some(Self::Section),
+            "submenu" => Some(Self::Submenu),
+            "item"    => Some(Self::Item),
+
+            _ => None
+        }
+    }
+
+    /// Get XML tag name of this menu part
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Menu    => "menu",
+            Self::Section => "section",
+            Self::Submenu => "submenu",
+            Self::Item    => "item"
+        }
+    }
+}
+
+/// Menu model or its part
+///
+/// `menu id { section { item { label: _("Quit"); action: "app.quit"; } } }`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Menu {
+    pub kind: MenuKind,
+    pub id: Option<String>,
+
+    /// Attributes as string properties and nested menu parts
+    pub children: Vec<Entry>
+}
+
+impl Menu {
+    pub fn entry(kind: MenuKind, id: Option<String>, children: Vec<Entry>) -> Entry {
+        Entry::Menu(Self { kind, id, children })
+    }
+
+    /// Get pretty string description of this entry
+    pub fn dbg(&self) -> String {
+        format!(
+            "Menu {{\n  kind: {},\n  id: {:?},\n  children: [\n{}  ]\n}}",
+            self.kind.as_str(),
+            self.id,
+            self.children.iter().map(|child| {
+                let text = child.dbg().lines()
+                    .map(|line| String::from("      ") + line + "\n")
+                    .collect::<String>();
+
+                text.trim_end().to_string() + ",\n"
+            }).collect::<String>()
+        )
+    }
+
+    /// Get XML description of this entry
+    pub fn get_xml(&self) -> String {
+        let beginning = match &self.id {
+            Some(id) => format!("<{} id=\"{}\">", self.kind.as_str(), id),
+            None => format!("<{}>", self.kind.as_str())
+        };
+
+        let mut attributes = String::new();
+        let mut children = String::new();
+
+        for child in &self.children {
+            match child {
+                Entry::Property(property) => {
+                    attributes += &match &property.value {
+                        PropertyValue::Translated(string) => format!(
+                            "<attribute name=\"{}\"{}>{}</attribute>",
+                            property.name,
+                            string.get_xml_attributes(),
+                            escape(&string.text)
+                        ),
+
+                        value => match value.get_literal() {
+                            Some(text) => format!("<attribute name=\"{}\">{}</attribute>", property.name, escape(text)),
+
+                            // Menu attributes can only be literals
+                            None => String::new()
+                        }
+                    };
+                }
+
+                child => children += &child.get_xml()
+            }
+        }
+
+        format!("{}{}{}</{}>", beginning, attributes, children, self.kind.as_str())
+    }
+}
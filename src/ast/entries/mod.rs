@@ -1,14 +1,24 @@
 pub mod root;
+pub mod type_name;
 pub mod object;
+pub mod template;
 pub mod property;
+pub mod expression;
+pub mod signal;
+pub mod menu;
 
 #[cfg(feature = "rhai-events")]
 pub mod rhai_event;
 
 pub mod prelude {
     pub use super::root::*;
+    pub use super::type_name::*;
     pub use super::object::*;
+    pub use super::template::*;
     pub use super::property::*;
+    pub use super::expression::*;
+    pub use super::signal::*;
+    pub use super::menu::*;
 
     #[cfg(feature = "rhai-events")]
     pub use super::rhai_event::*;
@@ -1,15 +1,46 @@
 use crate::ast::entry::Entry;
+use crate::ast::entries::type_name::TypeName;
 
+/// Child type annotation of the object
 #[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChildType {
+    /// `[titlebar]`
+    Type(String),
+
+    /// `[internal-child content_area]`
+    InternalChild(String),
+
+    /// `[action response=ok default]`
+    Action {
+        response: String,
+        default: bool
+    }
+}
+
+impl ChildType {
+    /// Get XML attributes of the `<child>` element
+    /// 
+    /// ` type="titlebar"`
+    pub fn get_xml_attributes(&self) -> String {
+        match self {
+            Self::Type(name) => format!(" type=\"{}\"", name),
+            Self::InternalChild(name) => format!(" internal-child=\"{}\"", name),
+            Self::Action { .. } => String::from(" type=\"action\"")
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Object {
-    pub class: String,
+    pub class: TypeName,
     pub name: Option<String>,
+    pub child_type: Option<ChildType>,
     pub children: Vec<Entry>
 }
 
 impl Object {
-    pub fn entry(class: String, name: Option<String>, children: Vec<Entry>) -> Entry {
-        Entry::Object(Self { class, name, children })
+    pub fn entry(class: TypeName, name: Option<String>, children: Vec<Entry>) -> Entry {
+        Entry::Object(Self { class, name, child_type: None, children })
     }
 
     pub fn add_child(&mut self, child: Entry) {
@@ -19,10 +50,11 @@ impl Object {
     /// Get pretty string description of this entry
     pub fn dbg(&self) -> String {
         format!(
-            "Object {{\n  class: {},\n  name: {:?},\n  children: [\n{}  ]\n}}",
+            "Object {{\n  class: {},\n  name: {:?},\n  child_type: {:?},\n  children: [\n{}  ]\n}}",
             self.class,
             self.name,
-            (&self.children).into_iter().map(|child| {
+            self.child_type,
+            self.children.iter().map(|child| {
                 let text = child.dbg().lines()
                     .map(|line| String::from("      ") + line + "\n")
                     .collect::<String>();
@@ -34,16 +66,23 @@ impl Object {
 
     /// Get XML description of this entry
     pub fn get_xml(&self) -> String {
-        let class = self.class.replace(".", "");
+        let class = self.class.get_gtype_name();
 
         let beginning = match &self.name {
             Some(name) => format!("<object class=\"{}\" id=\"{}\">", class, name),
             None => format!("<object class=\"{}\">", class)
         };
 
+        format!("{}{}</object>", beginning, self.get_xml_content())
+    }
+
+    /// Get XML description of this entry's signals, properties and children
+    pub fn get_xml_content(&self) -> String {
         let mut signals = String::new();
         let mut properties = String::new();
         let mut children = String::new();
+        let mut extensions = String::new();
+        let mut action_widgets = String::new();
 
         for child in &self.children {
             #[cfg(feature = "rhai-events")]
@@ -57,11 +96,41 @@ impl Object {
                 properties += &property.get_xml();
             }
 
+            else if let Entry::Signal(signal) = child {
+                signals += &signal.get_xml();
+            }
+
+            else if let Entry::Extension(extension) = child {
+                extensions += &extension.get_xml();
+            }
+
+            else if let Entry::Object(object) = child {
+                let attributes = match &object.child_type {
+                    Some(child_type) => child_type.get_xml_attributes(),
+                    None => String::new()
+                };
+
+                if let Some(ChildType::Action { response, default }) = &object.child_type {
+                    action_widgets += &format!(
+                        "<action-widget response=\"{}\"{}>{}</action-widget>",
+                        response,
+                        if *default { " default=\"yes\"" } else { "" },
+                        object.name.clone().unwrap_or_default()
+                    );
+                }
+
+                children += &format!("<child{}>{}</child>", attributes, object.get_xml());
+            }
+
             else {
                 children += &format!("<child>{}</child>", child.get_xml());
             }
         }
 
-        format!("{}{}{}{}</object>", beginning, signals, properties, children)
+        if !action_widgets.is_empty() {
+            action_widgets = format!("<action-widgets>{}</action-widgets>", action_widgets);
+        }
+
+        format!("{}{}{}{}{}", signals, properties, children, extensions, action_widgets)
     }
 }
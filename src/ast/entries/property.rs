@@ -1,22 +1,292 @@
 use crate::ast::entry::Entry;
 use crate::ast::entries::object::Object;
+use crate::ast::entries::expression::Expression;
+use crate::ast::entries::menu::Menu;
+use crate::ast::entries::root::Root;
+use crate::ast::entries::type_name::TypeName;
+use crate::ast::xml::{escape, cdata};
 
+/// Translatable string
+///
+/// `_("Hello")` or `C_("context", "Hello")`
 #[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranslatedString {
+    pub text: String,
+    pub context: Option<String>,
+
+    /// Comment for translators, `/* Translators: ... */`
+    pub comments: Option<String>
+}
+
+impl TranslatedString {
+    pub fn new<T: ToString>(text: T) -> Self {
+        Self {
+            text: text.to_string(),
+            context: None,
+            comments: None
+        }
+    }
+
+    /// Get pretty string description of this string
+    pub fn dbg(&self) -> String {
+        let comments = match &self.comments {
+            Some(comments) => format!("/* {} */ ", comments),
+            None => String::new()
+        };
+
+        match &self.context {
+            Some(context) => format!("{}C_({:?}, {:?})", comments, context, self.text),
+            None => format!("{}_({:?})", comments, self.text)
+        }
+    }
+
+    /// Get XML attributes of this string
+    ///
+    /// ` translatable="yes" context="..." comments="..."`
+    pub fn get_xml_attributes(&self) -> String {
+        let mut attributes = String::from(" translatable=\"yes\"");
+
+        if let Some(context) = &self.context {
+            attributes += &format!(" context=\"{}\"", escape(context));
+        }
+
+        if let Some(comments) = &self.comments {
+            attributes += &format!(" comments=\"{}\"", escape(comments));
+        }
+
+        attributes
+    }
+}
+
+/// String value which can be translatable, for the places
+/// which can't hold other property values
+///
+/// `"Hello"` or `_("Hello")`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StringValue {
+    String(String),
+    Translated(TranslatedString)
+}
+
+impl StringValue {
+    pub fn get_text(&self) -> &str {
+        match self {
+            Self::String(text) => text,
+            Self::Translated(string) => &string.text
+        }
+    }
+
+    /// Get pretty string description of this string
+    pub fn dbg(&self) -> String {
+        match self {
+            Self::String(text) => format!("{:?}", text),
+            Self::Translated(string) => string.dbg()
+        }
+    }
+
+    /// Get XML attributes of this string, empty if it's not translatable
+    pub fn get_xml_attributes(&self) -> String {
+        match self {
+            Self::String(_) => String::new(),
+            Self::Translated(string) => string.get_xml_attributes()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingFlag {
+    Bidirectional,
+    Inverted,
+    NoSyncCreate,
+    SyncCreate
+}
+
+impl BindingFlag {
+    pub fn parse(flag: &str) -> Option<Self> {
+        match flag {
+            "bidirectional"  => Some(Self::Bidirectional),
+            "inverted"       => Some(Self::Inverted),
+            "no-sync-create" => Some(Self::NoSyncCreate),
+            "sync-create"    => Some(Self::SyncCreate),
+
+            _ => None
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Bidirectional => "bidirectional",
+            Self::Inverted      => "inverted",
+            Self::NoSyncCreate  => "no-sync-create",
+            Self::SyncCreate    => "sync-create"
+        }
+    }
+}
+
+/// Property binding
+///
+/// `bind source.property inverted` or `bind template.item as <Gtk.StringObject>.string`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Binding {
+    pub expression: Expression,
+    pub flags: Vec<BindingFlag>
+}
+
+impl Binding {
+    /// Get source object and property names if the binding
+    /// is a single object property lookup, `source.property`
+    pub fn get_source(&self) -> Option<(&str, &str)> {
+        match &self.expression {
+            Expression::Lookup { name, type_name: None, source: Some(source) } => match source.as_ref() {
+                Expression::Object(object) => Some((object, name)),
+                _ => None
+            },
+
+            _ => None
+        }
+    }
+
+    /// Get pretty string description of this binding
+    pub fn dbg(&self) -> String {
+        let mut text = format!("bind {}", self.expression.dbg());
+
+        for flag in &self.flags {
+            text += " ";
+            text += flag.as_str();
+        }
+
+        text
+    }
+
+    /// Get GObject binding flags of this binding
+    ///
+    /// Bindings are synced on creation unless `no-sync-create` flag is specified
+    pub fn get_gflags(&self) -> String {
+        let mut flags = Vec::new();
+
+        if !self.flags.contains(&BindingFlag::NoSyncCreate) {
+            flags.push("sync-create");
+        }
+
+        if self.flags.contains(&BindingFlag::Bidirectional) {
+            flags.push("bidirectional");
+        }
+
+        if self.flags.contains(&BindingFlag::Inverted) {
+            flags.push("invert-boolean");
+        }
+
+        flags.join("|")
+    }
+
+    /// Get XML attributes of the single lookup binding,
+    /// or `None` if it must be described by the `<binding>` element
+    ///
+    /// ` bind-source="..." bind-property="..." bind-flags="..."`
+    pub fn get_xml_attributes(&self) -> Option<String> {
+        let (source, property) = self.get_source()?;
+
+        let flags = self.get_gflags();
+
+        if flags.is_empty() {
+            Some(format!(" bind-source=\"{}\" bind-property=\"{}\"", source, property))
+        }
+
+        else {
+            Some(format!(" bind-source=\"{}\" bind-property=\"{}\" bind-flags=\"{}\"", source, property, flags))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum PropertyValue {
-    Text(String),
+    /// `12`
+    Int(i64),
+
+    /// `1.5`
+    Float(f64),
+
+    /// `true`
+    Bool(bool),
+
+    /// Enum value or another identifier, `start`
+    Ident(String),
+
+    /// `top | bottom`
+    Flags(Vec<String>),
+
+    /// `null`
+    Null,
+
+    /// Identifier which is a name of an object from the same blueprint
+    ObjectRef(String),
+
+    /// `"Hello"`
+    String(String),
+
+    /// `typeof<Gtk.Box>`
+    TypeName(TypeName),
+
+    Translated(TranslatedString),
+
+    /// String list, `["Hello", _("World")]`
+    Array(Vec<PropertyValue>),
+
+    Binding(Binding),
+    Expression(Expression),
+
+    /// Inline menu, referenced by its id
+    Menu(Menu),
+
+    /// Nested interface with a single template, e.g. Gtk.BuilderListItemFactory `bytes`
+    Template(Root),
+
     Entry(Object)
 }
 
 impl PropertyValue {
     pub fn dbg(&self) -> String {
         match self {
-            PropertyValue::Text(text) => text.clone(),
-            PropertyValue::Entry(entry) => entry.dbg()
+            PropertyValue::String(text) => format!("{:?}", text),
+            PropertyValue::Flags(flags) => flags.join(" | "),
+            PropertyValue::TypeName(type_name) => format!("typeof<{}>", type_name),
+            PropertyValue::Translated(string) => string.dbg(),
+            PropertyValue::Array(items) => format!("[{}]", items.iter().map(|item| item.dbg()).collect::<Vec<String>>().join(", ")),
+            PropertyValue::Binding(binding) => binding.dbg(),
+            PropertyValue::Expression(expression) => format!("expr {}", expression.dbg()),
+            PropertyValue::Menu(menu) => menu.dbg(),
+            PropertyValue::Template(interface) => interface.dbg(),
+            PropertyValue::Entry(entry) => entry.dbg(),
+
+            PropertyValue::Null => String::from("null"),
+
+            value => value.get_literal().unwrap_or_default()
+        }
+    }
+
+    /// Get unescaped XML text of the literal value,
+    /// or `None` if the value is not a literal
+    /// 
+    /// `top | bottom` -> `top|bottom`, `typeof<Gtk.Box>` -> `GtkBox`
+    pub fn get_literal(&self) -> Option<String> {
+        match self {
+            PropertyValue::Int(value) => Some(value.to_string()),
+            PropertyValue::Float(value) => Some(value.to_string()),
+            PropertyValue::Bool(value) => Some(value.to_string()),
+            PropertyValue::Flags(flags) => Some(flags.join("|")),
+            PropertyValue::Null => Some(String::new()),
+            PropertyValue::TypeName(type_name) => Some(type_name.get_gtype_name()),
+
+            PropertyValue::Ident(value) |
+            PropertyValue::ObjectRef(value) |
+            PropertyValue::String(value) => Some(value.clone()),
+
+            _ => None
         }
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Property {
     pub name: String,
     pub value: PropertyValue
@@ -38,11 +308,48 @@ impl Property {
 
     /// Get XML description of this entry
     pub fn get_xml(&self) -> String {
-        format!("<property name=\"{}\">{}</property>", self.name, {
-            match &self.value {
-                PropertyValue::Text(text) => text.clone(),
-                PropertyValue::Entry(entry) => entry.get_xml()
+        self.get_xml_element("property")
+    }
+
+    /// Get XML description of this entry using the given tag name,
+    /// e.g. `<relation name="labelled-by">label</relation>`
+    pub fn get_xml_element(&self, tag: &str) -> String {
+        let (attributes, value) = match &self.value {
+            PropertyValue::Binding(binding) => {
+                return match binding.get_xml_attributes() {
+                    Some(attributes) => format!("<{} name=\"{}\"{}/>", tag, self.name, attributes),
+
+                    // <binding name="label"><lookup name="string">...</lookup></binding>
+                    None => format!("<binding name=\"{}\">{}</binding>", self.name, binding.expression.get_xml())
+                };
             }
-        })
+
+            PropertyValue::Translated(string) => (string.get_xml_attributes(), escape(&string.text)),
+
+            // GtkBuilder reads strv as newline-separated strings and can only
+            // translate the whole property, so items share the same attributes
+            PropertyValue::Array(items) => {
+                let attributes = items.iter().find_map(|item| match item {
+                    PropertyValue::Translated(string) => Some(string.get_xml_attributes()),
+                    _ => None
+                });
+
+                let text = items.iter().map(|item| match item {
+                    PropertyValue::Translated(string) => string.text.clone(),
+                    item => item.get_literal().unwrap_or_default()
+                }).collect::<Vec<String>>().join("\n");
+
+                (attributes.unwrap_or_default(), escape(text))
+            }
+
+            PropertyValue::Expression(expression) => (String::new(), expression.get_xml()),
+            PropertyValue::Menu(menu) => (String::new(), menu.id.clone().unwrap_or_default()),
+            PropertyValue::Template(interface) => (String::new(), cdata(interface.get_xml())),
+            PropertyValue::Entry(entry) => (String::new(), entry.get_xml()),
+
+            value => (String::new(), escape(value.get_literal().unwrap_or_default()))
+        };
+
+        format!("<{} name=\"{}\"{}>{}</{}>", tag, self.name, attributes, value, tag)
     }
 }
@@ -1,46 +1,113 @@
+use std::borrow::Cow;
+use std::fmt;
+
 use crate::ast::entry::Entry;
+use crate::ast::xml::escape;
+
+/// Library version from the use statement
+///
+/// `4.0` or `1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32
+}
+
+impl Version {
+    pub fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+
+    /// Parse version in `major` or `major.minor` format
+    ///
+    /// ```
+    /// use gtk_ui_builder::ast::entries::root::Version;
+    ///
+    /// assert_eq!(Version::parse("4.0"), Some(Version::new(4, 0)));
+    /// assert_eq!(Version::parse("1"), Some(Version::new(1, 0)));
+    ///
+    /// assert!(Version::parse("4.0.1").is_none());
+    /// assert!(Version::parse("-1").is_none());
+    /// ```
+    pub fn parse(version: &str) -> Option<Self> {
+        fn parse_number(number: &str) -> Option<u32> {
+            if !number.is_empty() && number.chars().all(|char| char.is_ascii_digit()) {
+                number.parse().ok()
+            } else {
+                None
+            }
+        }
+
+        match version.split_once('.') {
+            Some((major, minor)) => Some(Self::new(parse_number(major)?, parse_number(minor)?)),
+            None => Some(Self::new(parse_number(version)?, 0))
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Requirement {
-    Gtk(String),
-    Libadwaita(String),
-    Other(String, String)
+    Gtk(Version),
+    Libadwaita(Version),
+    Other(String, Version)
 }
 
 impl Requirement {
-    pub fn new<T: ToString>(lib: T, version: T) -> Self {
-        let version = version.to_string();
+    /// Create requirement of the imported namespace
+    ///
+    /// `using Gtk 4.0` or `using Adw 1`
+    pub fn new<T: ToString>(namespace: T, version: Version) -> Self {
+        match namespace.to_string().as_str() {
+            "Gtk" | "gtk" | "GTK" => Self::Gtk(version),
+            "Adw" | "adw" | "ADW" | "libadwaita" | "Libadwaita" => Self::Libadwaita(version),
 
-        match lib.to_string().as_str() {
-            "gtk" => Self::Gtk(version),
-            "Gtk" => Self::Gtk(version),
-            "GTK" => Self::Gtk(version),
+            namespace => Self::Other(namespace.to_string(), version)
+        }
+    }
 
-            "adw" => Self::Libadwaita(version),
-            "Adw" => Self::Libadwaita(version),
-            "ADW" => Self::Libadwaita(version),
-            "libadwaita" => Self::Libadwaita(version),
-            "Libadwaita" => Self::Libadwaita(version),
+    /// Get name of the imported namespace
+    pub fn get_namespace(&self) -> &str {
+        match self {
+            Requirement::Gtk(_) => "Gtk",
+            Requirement::Libadwaita(_) => "Adw",
+            Requirement::Other(namespace, _) => namespace
+        }
+    }
 
-            lib => Self::Other(lib.to_string(), version)
+    pub fn get_version(&self) -> Version {
+        match self {
+            Requirement::Gtk(version) |
+            Requirement::Libadwaita(version) |
+            Requirement::Other(_, version) => *version
         }
     }
 
+    /// Get XML requirement of the library. Other namespaces
+    /// are not libraries GtkBuilder can check, so they have no XML
     pub fn get_xml(&self) -> Option<String> {
         let (lib, version) = match self {
             Requirement::Gtk(version) => ("gtk", version),
-            Requirement::Other(lib, version) => (lib.as_str(), version),
-
-            Requirement::Libadwaita(_) => return None // ("libadwaita", version)
+            Requirement::Libadwaita(version) => ("libadwaita", version),
+            Requirement::Other(_, _) => return None
         };
 
         Some(format!("<requires lib=\"{}\" version=\"{}\"/>", lib, version))
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq)]
 pub struct Root {
     pub requirements: Vec<Requirement>,
+
+    /// Gettext domain of the translatable strings, `translation-domain "myapp";`
+    pub translation_domain: Option<String>,
+
     pub children: Vec<Entry>
 }
 
@@ -53,12 +120,17 @@ impl Root {
         self.requirements.push(requirement);
     }
 
+    pub fn set_translation_domain<T: ToString>(&mut self, domain: T) {
+        self.translation_domain = Some(domain.to_string());
+    }
+
     /// Get pretty string description of this entry
     pub fn dbg(&self) -> String {
         format!(
-            "Root {{\n  requirements: {:?},\n  children: [\n{}  ]\n}}",
+            "Root {{\n  requirements: {:?},\n  translation_domain: {:?},\n  children: [\n{}  ]\n}}",
             self.requirements,
-            (&self.children).into_iter().map(|child| {
+            self.translation_domain,
+            self.children.iter().map(|child| {
                 let text = child.dbg().lines()
                     .map(|line| String::from("      ") + line + "\n")
                     .collect::<String>();
@@ -70,13 +142,55 @@ impl Root {
 
     /// Get XML description of this entry
     pub fn get_xml(&self) -> String {
+        let domain = match &self.translation_domain {
+            Some(domain) => format!(" domain=\"{}\"", escape(domain)),
+            None => String::new()
+        };
+
+        // Nested interfaces are translated within the same domain
+        let children = match &self.translation_domain {
+            Some(domain) => {
+                let mut children = self.children.clone();
+
+                Self::set_nested_translation_domain(&mut children, domain);
+
+                Cow::Owned(children)
+            }
+
+            None => Cow::Borrowed(&self.children)
+        };
+
         format!(
-            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface>{}{}</interface>",
-            (&self.requirements).into_iter().map(|item| item.get_xml()).filter_map(|item| item).collect::<String>(),
-            (&self.children).into_iter().map(|item| item.get_xml()).collect::<String>()
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface{}>{}{}{}</interface>",
+            domain,
+            self.requirements.iter().filter_map(|item| item.get_xml()).collect::<String>(),
+            children.iter().map(|item| item.get_xml()).collect::<String>(),
+            self.get_inline_menus().iter().map(|menu| menu.get_xml()).collect::<String>()
         )
     }
 
+    /// Set translation domain of the nested interfaces which don't have their own
+    fn set_nested_translation_domain(entries: &mut [Entry], domain: &str) {
+        for entry in entries {
+            match entry {
+                Entry::Object(obj) => Self::set_nested_translation_domain(&mut obj.children, domain),
+                Entry::Template(template) => Self::set_nested_translation_domain(&mut template.children, domain),
+
+                Entry::Property(prop) => match &mut prop.value {
+                    super::property::PropertyValue::Template(interface) if interface.translation_domain.is_none() => {
+                        interface.set_translation_domain(domain);
+                    }
+
+                    super::property::PropertyValue::Entry(obj) => Self::set_nested_translation_domain(&mut obj.children, domain),
+
+                    _ => ()
+                },
+
+                _ => ()
+            }
+        }
+    }
+
     pub fn filter<T: Fn(&Entry) -> Option<F>, F>(&self, filter: &T) -> Vec<F> {
         fn filter_entries<T: Fn(&Entry) -> Option<F>, F>(entries: &Vec<Entry>, filter: &T) -> Vec<F> {
             let mut filtered = Vec::new();
@@ -88,6 +202,14 @@ impl Root {
 
                 match entry {
                     Entry::Object(obj) => filtered.append(&mut filter_entries(&obj.children, filter)),
+                    Entry::Template(template) => {
+                        if let Some(found) = filter(&Entry::Object(template.get_object())) {
+                            filtered.push(found);
+                        }
+
+                        filtered.append(&mut filter_entries(&template.children, filter));
+                    }
+                    // Nested templates are separate interfaces with their own entries
                     Entry::Property(prop) => {
                         if let super::property::PropertyValue::Entry(obj) = &prop.value {
                             if let Some(found) = filter(&Entry::Object(obj.clone())) {
@@ -107,23 +229,39 @@ impl Root {
         filter_entries(&self.children, filter)
     }
 
-    pub fn get_named_objects(&self) -> Vec<(String, super::object::Object)> {
+    /// Get menus defined as properties values. GtkBuilder only accepts
+    /// menus at the root, so they're placed there and referenced by id
+    pub fn get_inline_menus(&self) -> Vec<super::menu::Menu> {
         self.filter(&|entry| {
             match entry {
-                Entry::Object(obj) => {
-                    if let Some(name) = &obj.name {
-                        Some((name.clone(), obj.clone()))
-                    }
-
-                    else {
-                        None
-                    }
+                Entry::Property(property) => match &property.value {
+                    super::property::PropertyValue::Menu(menu) => Some(menu.clone()),
+                    _ => None
                 },
                 _ => None
             }
         })
     }
 
+    /// Get composite template defined at the root
+    pub fn get_template(&self) -> Option<&super::template::Template> {
+        self.children.iter().find_map(|entry| {
+            match entry {
+                Entry::Template(template) => Some(template),
+                _ => None
+            }
+        })
+    }
+
+    pub fn get_named_objects(&self) -> Vec<(String, super::object::Object)> {
+        self.filter(&|entry| {
+            match entry {
+                Entry::Object(obj) => obj.name.as_ref().map(|name| (name.clone(), obj.clone())),
+                _ => None
+            }
+        })
+    }
+
     #[cfg(feature = "rhai-events")]
     pub fn get_rhai_events(&self) -> Vec<super::rhai_event::RhaiEvent> {
         self.filter(&|entry| {
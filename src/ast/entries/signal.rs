@@ -0,0 +1,70 @@
+use crate::ast::entry::Entry;
+
+/// Signal handler connection
+///
+/// `clicked => $on_clicked() swapped after;`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signal {
+    /// Signal name with optional detail, `notify::label`
+    pub name: String,
+
+    pub handler: String,
+
+    /// Object passed to the handler instead of the emitter
+    pub object: Option<String>,
+
+    /// `Some(true)` for `swapped`, `Some(false)` for `not-swapped`
+    pub swapped: Option<bool>,
+
+    pub after: bool
+}
+
+impl Signal {
+    pub fn entry(name: String, handler: String, object: Option<String>) -> Entry {
+        Entry::Signal(Self {
+            name,
+            handler,
+            object,
+            swapped: None,
+            after: false
+        })
+    }
+
+    /// Get pretty string description of this entry
+    pub fn dbg(&self) -> String {
+        let mut text = format!("{} => ${}({})", self.name, self.handler, self.object.clone().unwrap_or_default());
+
+        match self.swapped {
+            Some(true) => text += " swapped",
+            Some(false) => text += " not-swapped",
+            None => ()
+        }
+
+        if self.after {
+            text += " after";
+        }
+
+        text
+    }
+
+    /// Get XML description of this entry
+    pub fn get_xml(&self) -> String {
+        let mut attributes = format!("name=\"{}\" handler=\"{}\"", self.name, self.handler);
+
+        if let Some(object) = &self.object {
+            attributes += &format!(" object=\"{}\"", object);
+        }
+
+        match self.swapped {
+            Some(true) => attributes += " swapped=\"yes\"",
+            Some(false) => attributes += " swapped=\"no\"",
+            None => ()
+        }
+
+        if self.after {
+            attributes += " after=\"yes\"";
+        }
+
+        format!("<signal {}/>", attributes)
+    }
+}
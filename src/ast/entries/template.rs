@@ -0,0 +1,68 @@
+use crate::ast::entry::Entry;
+use crate::ast::entries::object::Object;
+use crate::ast::entries::root::Root;
+use crate::ast::entries::type_name::TypeName;
+
+/// Composite template
+///
+/// `template $ClassName : Parent.Type {}`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    /// Template class, `$ClassName`
+    pub class: TypeName,
+    pub parent: Option<TypeName>,
+    pub children: Vec<Entry>
+}
+
+impl Template {
+    pub fn entry(class: TypeName, parent: Option<TypeName>, children: Vec<Entry>) -> Entry {
+        Entry::Template(Self { class, parent, children })
+    }
+
+    /// Get template as an object named `template`, the way
+    /// the other entries can reference it
+    pub fn get_object(&self) -> Object {
+        Object {
+            class: self.class.clone(),
+            name: Some(String::from("template")),
+            child_type: None,
+            children: self.children.clone()
+        }
+    }
+
+    /// Get pretty string description of this entry
+    pub fn dbg(&self) -> String {
+        format!(
+            "Template {{\n  class: {},\n  parent: {:?},\n  children: [\n{}  ]\n}}",
+            self.class,
+            self.parent.as_ref().map(|parent| parent.to_string()),
+            self.children.iter().map(|child| {
+                let text = child.dbg().lines()
+                    .map(|line| String::from("      ") + line + "\n")
+                    .collect::<String>();
+
+                text.trim_end().to_string() + ",\n"
+            }).collect::<String>()
+        )
+    }
+
+    /// Get XML description of this entry
+    pub fn get_xml(&self) -> String {
+        let beginning = match &self.parent {
+            Some(parent) => format!("<template class=\"{}\" parent=\"{}\">", self.class.get_gtype_name(), parent.get_gtype_name()),
+            None => format!("<template class=\"{}\">", self.class.get_gtype_name())
+        };
+
+        format!("{}{}</template>", beginning, self.get_object().get_xml_content())
+    }
+
+    /// Get the whole interface containing only this template,
+    /// e.g. for the Gtk.BuilderListItemFactory `bytes` property
+    pub fn into_interface(self) -> Root {
+        Root {
+            requirements: Vec::new(),
+            translation_domain: None,
+            children: vec![Entry::Template(self)]
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt;
+
+/// C type prefixes of the namespaces which differ from their names
+/// or are commonly used in blueprints. Other namespaces use their name as the prefix
+const NAMESPACE_PREFIXES: &[(&str, &str)] = &[
+    ("Gtk",       "Gtk"),
+    ("Gdk",       "Gdk"),
+    ("Gsk",       "Gsk"),
+    ("Adw",       "Adw"),
+    ("GLib",      "G"),
+    ("GObject",   "G"),
+    ("Gio",       "G"),
+    ("Pango",     "Pango"),
+    ("GtkSource", "GtkSource"),
+    ("Shumate",   "Shumate"),
+    ("Vte",       "Vte"),
+    ("WebKit",    "WebKit"),
+    ("Panel",     "Panel")
+];
+
+/// Get C type prefix of the namespace
+///
+/// ```
+/// use gtk_ui_builder::ast::entries::type_name::get_namespace_prefix;
+///
+/// assert_eq!(get_namespace_prefix("Gio"), "G");
+/// assert_eq!(get_namespace_prefix("Adw"), "Adw");
+/// assert_eq!(get_namespace_prefix("MyLib"), "MyLib");
+/// ```
+pub fn get_namespace_prefix(namespace: &str) -> &str {
+    NAMESPACE_PREFIXES.iter()
+        .find(|(name, _)| *name == namespace)
+        .map(|(_, prefix)| *prefix)
+        .unwrap_or(namespace)
+}
+
+/// Object type name
+///
+/// `Gtk.Box` or `$MyAppSidebar` for the types registered by the application
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeName {
+    pub namespace: Option<String>,
+    pub name: String,
+
+    /// GType name is used as-is, `$MyAppSidebar`
+    pub external: bool
+}
+
+impl TypeName {
+    /// Parse blueprint type name
+    ///
+    /// ```
+    /// use gtk_ui_builder::ast::entries::type_name::TypeName;
+    ///
+    /// let type_name = TypeName::parse("Gtk.Box").unwrap();
+    ///
+    /// assert_eq!(type_name.namespace, Some(String::from("Gtk")));
+    /// assert_eq!(type_name.get_gtype_name(), "GtkBox");
+    ///
+    /// assert!(TypeName::parse("$MyAppSidebar").unwrap().external);
+    /// assert!(TypeName::parse("Gtk.").is_none());
+    /// ```
+    pub fn parse(type_name: &str) -> Option<Self> {
+        fn is_identifier(text: &str) -> bool {
+            !text.is_empty() && text.chars().all(|char| char.is_alphanumeric() || char == '_')
+        }
+
+        if let Some(name) = type_name.strip_prefix('$') {
+            return is_identifier(name).then(|| Self {
+                namespace: None,
+                name: name.to_string(),
+                external: true
+            });
+        }
+
+        match type_name.split_once('.') {
+            Some((namespace, name)) if is_identifier(namespace) && is_identifier(name) => Some(Self {
+                namespace: Some(namespace.to_string()),
+                name: name.to_string(),
+                external: false
+            }),
+
+            None if is_identifier(type_name) => Some(Self {
+                namespace: None,
+                name: type_name.to_string(),
+                external: false
+            }),
+
+            _ => None
+        }
+    }
+
+    /// Get GType name of this type
+    ///
+    /// `Gtk.Box` -> `GtkBox`, `Gio.File` -> `GFile`, `$MyAppSidebar` -> `MyAppSidebar`
+    pub fn get_gtype_name(&self) -> String {
+        match &self.namespace {
+            Some(namespace) => format!("{}{}", get_namespace_prefix(namespace), self.name),
+            None => self.name.clone()
+        }
+    }
+}
+
+impl fmt::Display for TypeName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.namespace, self.external) {
+            (_, true) => write!(f, "${}", self.name),
+            (Some(namespace), false) => write!(f, "{}.{}", namespace, self.name),
+            (None, false) => write!(f, "{}", self.name)
+        }
+    }
+}
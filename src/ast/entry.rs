@@ -1,10 +1,15 @@
 use super::entries::prelude::*;
+use super::extension::Extension;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Entry {
     Root(Root),
     Object(Object),
+    Template(Template),
     Property(Property),
+    Signal(Signal),
+    Menu(Menu),
+    Extension(Extension),
 
     #[cfg(feature = "rhai-events")]
     RhaiEvent(RhaiEvent)
@@ -15,7 +20,11 @@ impl Entry {
         match self {
             Self::Root(obj) => obj.dbg(),
             Self::Object(obj) => obj.dbg(),
+            Self::Template(obj) => obj.dbg(),
             Self::Property(obj) => obj.dbg(),
+            Self::Signal(obj) => obj.dbg(),
+            Self::Menu(obj) => obj.dbg(),
+            Self::Extension(obj) => obj.dbg(),
 
             #[cfg(feature = "rhai-events")]
             Self::RhaiEvent(obj) => obj.dbg()
@@ -26,7 +35,11 @@ impl Entry {
         match self {
             Self::Root(obj) => obj.get_xml(),
             Self::Object(obj) => obj.get_xml(),
+            Self::Template(obj) => obj.get_xml(),
             Self::Property(obj) => obj.get_xml(),
+            Self::Signal(obj) => obj.get_xml(),
+            Self::Menu(obj) => obj.get_xml(),
+            Self::Extension(obj) => obj.get_xml(),
 
             #[cfg(feature = "rhai-events")]
             Self::RhaiEvent(obj) => obj.get_xml()
@@ -0,0 +1,52 @@
+use super::extensions::prelude::*;
+
+/// Object-specific blueprint blocks which are
+/// translated into the GtkBuildable custom XML
+#[derive(Debug, Clone, PartialEq)]
+pub enum Extension {
+    Styles(Styles),
+    Layout(Layout),
+    Accessibility(Accessibility),
+    Strings(Strings),
+    Items(Items),
+    Attributes(Attributes),
+    Widgets(Widgets),
+    FileFilter(FileFilter),
+    Condition(Condition),
+    Setters(Setters),
+    Responses(Responses)
+}
+
+impl Extension {
+    pub fn dbg(&self) -> String {
+        match self {
+            Self::Styles(obj) => obj.dbg(),
+            Self::Layout(obj) => obj.dbg(),
+            Self::Accessibility(obj) => obj.dbg(),
+            Self::Strings(obj) => obj.dbg(),
+            Self::Items(obj) => obj.dbg(),
+            Self::Attributes(obj) => obj.dbg(),
+            Self::Widgets(obj) => obj.dbg(),
+            Self::FileFilter(obj) => obj.dbg(),
+            Self::Condition(obj) => obj.dbg(),
+            Self::Setters(obj) => obj.dbg(),
+            Self::Responses(obj) => obj.dbg()
+        }
+    }
+
+    pub fn get_xml(&self) -> String {
+        match self {
+            Self::Styles(obj) => obj.get_xml(),
+            Self::Layout(obj) => obj.get_xml(),
+            Self::Accessibility(obj) => obj.get_xml(),
+            Self::Strings(obj) => obj.get_xml(),
+            Self::Items(obj) => obj.get_xml(),
+            Self::Attributes(obj) => obj.get_xml(),
+            Self::Widgets(obj) => obj.get_xml(),
+            Self::FileFilter(obj) => obj.get_xml(),
+            Self::Condition(obj) => obj.get_xml(),
+            Self::Setters(obj) => obj.get_xml(),
+            Self::Responses(obj) => obj.get_xml()
+        }
+    }
+}
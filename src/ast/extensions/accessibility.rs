@@ -0,0 +1,86 @@
+use crate::ast::entry::Entry;
+use crate::ast::extension::Extension;
+use crate::ast::entries::property::Property;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessibleKind {
+    Property,
+    Relation,
+    State
+}
+
+impl AccessibleKind {
+    /// Get kind of the accessible attribute by its name
+    pub fn of(name: &str) -> Option<Self> {
+        match name {
+            "autocomplete" | "description" | "has-popup" | "help-text" | "key-shortcuts" |
+            "label" | "level" | "modal" | "multi-line" | "multi-selectable" | "orientation" |
+            "placeholder" | "read-only" | "required" | "role-description" | "sort" |
+            "value-max" | "value-min" | "value-now" | "value-text" => Some(Self::Property),
+
+            "active-descendant" | "col-count" | "col-index" | "col-index-text" | "col-span" |
+            "controls" | "described-by" | "details" | "error-message" | "flow-to" |
+            "labelled-by" | "owns" | "pos-in-set" | "row-count" | "row-index" |
+            "row-index-text" | "row-span" | "set-size" => Some(Self::Relation),
+
+            "busy" | "checked" | "disabled" | "expanded" | "hidden" |
+            "invalid" | "pressed" | "selected" | "visited" => Some(Self::State),
+
+            _ => None
+        }
+    }
+
+    /// Check if the relation refers to other objects
+    pub fn is_object_relation(name: &str) -> bool {
+        matches!(name, "active-descendant" | "controls" | "described-by" | "details" | "error-message" | "flow-to" | "labelled-by" | "owns")
+    }
+
+    /// Get XML tag name of this kind
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Property => "property",
+            Self::Relation => "relation",
+            Self::State    => "state"
+        }
+    }
+}
+
+/// Accessible properties, relations and states
+///
+/// `accessibility { label: _("Close"); labelled-by: title; }`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Accessibility {
+    pub properties: Vec<Property>
+}
+
+impl Accessibility {
+    pub fn entry(properties: Vec<Property>) -> Entry {
+        Entry::Extension(Extension::Accessibility(Self { properties }))
+    }
+
+    /// Get pretty string description of this extension
+    pub fn dbg(&self) -> String {
+        format!(
+            "Accessibility {{\n  properties: [\n{}  ]\n}}",
+            self.properties.iter().map(|property| {
+                let text = property.dbg().lines()
+                    .map(|line| String::from("      ") + line + "\n")
+                    .collect::<String>();
+
+                text.trim_end().to_string() + ",\n"
+            }).collect::<String>()
+        )
+    }
+
+    /// Get XML description of this extension
+    pub fn get_xml(&self) -> String {
+        format!(
+            "<accessibility>{}</accessibility>",
+            self.properties.iter().map(|property| {
+                let kind = AccessibleKind::of(&property.name).unwrap_or(AccessibleKind::Property);
+
+                property.get_xml_element(kind.as_str())
+            }).collect::<String>()
+        )
+    }
+}
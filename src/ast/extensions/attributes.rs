@@ -0,0 +1,56 @@
+use crate::ast::entry::Entry;
+use crate::ast::extension::Extension;
+use crate::ast::xml::escape;
+
+/// Pango attributes names supported by GtkBuilder
+pub const PANGO_ATTRIBUTES: &[&str] = &[
+    "language", "family", "style", "weight", "variant", "stretch", "underline",
+    "strikethrough", "gravity", "gravity-hint", "font-desc", "foreground", "background",
+    "underline-color", "strikethrough-color", "scale", "size", "absolute-size", "rise",
+    "fallback", "letter-spacing", "font-features", "foreground-alpha", "background-alpha",
+    "allow-breaks", "show", "insert-hyphens", "overline", "overline-color", "line-height",
+    "absolute-line-height", "text-transform", "word", "sentence", "baseline-shift", "font-scale"
+];
+
+/// Pango attribute with its literal value
+///
+/// `weight: bold;`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attribute {
+    pub name: String,
+    pub value: String
+}
+
+/// Gtk.Label pango attributes
+///
+/// `attributes { weight: bold; scale: 1.2; }`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attributes {
+    pub attributes: Vec<Attribute>
+}
+
+impl Attributes {
+    pub fn entry(attributes: Vec<Attribute>) -> Entry {
+        Entry::Extension(Extension::Attributes(Self { attributes }))
+    }
+
+    /// Get pretty string description of this extension
+    pub fn dbg(&self) -> String {
+        format!(
+            "Attributes {{\n{}}}",
+            self.attributes.iter()
+                .map(|attribute| format!("  {}: {},\n", attribute.name, attribute.value))
+                .collect::<String>()
+        )
+    }
+
+    /// Get XML description of this extension
+    pub fn get_xml(&self) -> String {
+        format!(
+            "<attributes>{}</attributes>",
+            self.attributes.iter()
+                .map(|attribute| format!("<attribute name=\"{}\" value=\"{}\"/>", attribute.name, escape(&attribute.value)))
+                .collect::<String>()
+        )
+    }
+}
@@ -0,0 +1,82 @@
+use crate::ast::entry::Entry;
+use crate::ast::extension::Extension;
+use crate::ast::entries::property::StringValue;
+use crate::ast::xml::escape;
+
+/// Adw.Breakpoint condition
+///
+/// `condition ("max-width: 500sp")`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition {
+    pub condition: String
+}
+
+impl Condition {
+    pub fn entry<T: ToString>(condition: T) -> Entry {
+        Entry::Extension(Extension::Condition(Self { condition: condition.to_string() }))
+    }
+
+    /// Get pretty string description of this extension
+    pub fn dbg(&self) -> String {
+        format!("Condition({:?})", self.condition)
+    }
+
+    /// Get XML description of this extension
+    pub fn get_xml(&self) -> String {
+        format!("<condition>{}</condition>", escape(&self.condition))
+    }
+}
+
+/// Property value applied by the breakpoint
+///
+/// `split_view.collapsed: true;`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setter {
+    pub object: String,
+    pub property: String,
+
+    /// Literal value text or translatable string
+    pub value: StringValue
+}
+
+impl Setter {
+    /// Get XML description of this setter
+    pub fn get_xml(&self) -> String {
+        format!(
+            "<setter object=\"{}\" property=\"{}\"{}>{}</setter>",
+            self.object,
+            self.property,
+            self.value.get_xml_attributes(),
+            escape(self.value.get_text())
+        )
+    }
+}
+
+/// Adw.Breakpoint setters
+///
+/// `setters { split_view.collapsed: true; }`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Setters {
+    pub setters: Vec<Setter>
+}
+
+impl Setters {
+    pub fn entry(setters: Vec<Setter>) -> Entry {
+        Entry::Extension(Extension::Setters(Self { setters }))
+    }
+
+    /// Get pretty string description of this extension
+    pub fn dbg(&self) -> String {
+        format!(
+            "Setters {{\n{}}}",
+            self.setters.iter()
+                .map(|setter| format!("  {}.{}: {},\n", setter.object, setter.property, setter.value.dbg()))
+                .collect::<String>()
+        )
+    }
+
+    /// Get XML description of this extension
+    pub fn get_xml(&self) -> String {
+        self.setters.iter().map(|setter| setter.get_xml()).collect()
+    }
+}
@@ -0,0 +1,72 @@
+use crate::ast::entry::Entry;
+use crate::ast::extension::Extension;
+use crate::ast::xml::escape;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileFilterKind {
+    MimeTypes,
+    Patterns,
+    Suffixes
+}
+
+impl FileFilterKind {
+    pub fn parse(keyword: &str) -> Option<Self> {
+        match keyword {
+            "mime-types" => Some(Self::MimeTypes),
+            "patterns"   => Some(Self::Patterns),
+            "suffixes"   => Some(Self::Suffixes),
+
+            _ => None
+        }
+    }
+
+    /// Get XML tag name of the values list
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MimeTypes => "mime-types",
+            Self::Patterns  => "patterns",
+            Self::Suffixes  => "suffixes"
+        }
+    }
+
+    /// Get XML tag name of the single value
+    pub fn item_tag(&self) -> &'static str {
+        match self {
+            Self::MimeTypes => "mime-type",
+            Self::Patterns  => "pattern",
+            Self::Suffixes  => "suffix"
+        }
+    }
+}
+
+/// Gtk.FileFilter rules
+///
+/// `mime-types ["image/png", "image/*"]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileFilter {
+    pub kind: FileFilterKind,
+    pub values: Vec<String>
+}
+
+impl FileFilter {
+    pub fn entry(kind: FileFilterKind, values: Vec<String>) -> Entry {
+        Entry::Extension(Extension::FileFilter(Self { kind, values }))
+    }
+
+    /// Get pretty string description of this extension
+    pub fn dbg(&self) -> String {
+        format!("FileFilter {} {:?}", self.kind.as_str(), self.values)
+    }
+
+    /// Get XML description of this extension
+    pub fn get_xml(&self) -> String {
+        let tag = self.kind.item_tag();
+
+        format!(
+            "<{}>{}</{}>",
+            self.kind.as_str(),
+            self.values.iter().map(|value| format!("<{}>{}</{}>", tag, escape(value), tag)).collect::<String>(),
+            self.kind.as_str()
+        )
+    }
+}
@@ -0,0 +1,88 @@
+use crate::ast::entry::Entry;
+use crate::ast::extension::Extension;
+use crate::ast::entries::property::StringValue;
+use crate::ast::xml::escape;
+
+/// Get `<item>` element of the string value
+fn get_item_xml(id: Option<&String>, value: &StringValue) -> String {
+    let id = match id {
+        Some(id) => format!(" id=\"{}\"", escape(id)),
+        None => String::new()
+    };
+
+    format!("<item{}{}>{}</item>", id, value.get_xml_attributes(), escape(value.get_text()))
+}
+
+/// Gtk.StringList strings
+///
+/// `strings ["a", _("b")]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Strings {
+    pub strings: Vec<StringValue>
+}
+
+impl Strings {
+    pub fn entry(strings: Vec<StringValue>) -> Entry {
+        Entry::Extension(Extension::Strings(Self { strings }))
+    }
+
+    /// Get pretty string description of this extension
+    pub fn dbg(&self) -> String {
+        format!(
+            "Strings [{}]",
+            self.strings.iter().map(|string| string.dbg()).collect::<Vec<String>>().join(", ")
+        )
+    }
+
+    /// Get XML description of this extension
+    pub fn get_xml(&self) -> String {
+        format!(
+            "<items>{}</items>",
+            self.strings.iter().map(|string| get_item_xml(None, string)).collect::<String>()
+        )
+    }
+}
+
+/// Gtk.ComboBoxText item
+///
+/// `id: "label"`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    pub id: Option<String>,
+    pub value: StringValue
+}
+
+/// Gtk.ComboBoxText items
+///
+/// `items [id: "label", _("Other label")]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Items {
+    pub items: Vec<Item>
+}
+
+impl Items {
+    pub fn entry(items: Vec<Item>) -> Entry {
+        Entry::Extension(Extension::Items(Self { items }))
+    }
+
+    /// Get pretty string description of this extension
+    pub fn dbg(&self) -> String {
+        format!(
+            "Items [{}]",
+            self.items.iter().map(|item| {
+                match &item.id {
+                    Some(id) => format!("{}: {}", id, item.value.dbg()),
+                    None => item.value.dbg()
+                }
+            }).collect::<Vec<String>>().join(", ")
+        )
+    }
+
+    /// Get XML description of this extension
+    pub fn get_xml(&self) -> String {
+        format!(
+            "<items>{}</items>",
+            self.items.iter().map(|item| get_item_xml(item.id.as_ref(), &item.value)).collect::<String>()
+        )
+    }
+}
@@ -0,0 +1,39 @@
+use crate::ast::entry::Entry;
+use crate::ast::extension::Extension;
+use crate::ast::entries::property::Property;
+
+/// Child properties of the parent's layout manager
+///
+/// `layout { column: 1; row: 2; }`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Layout {
+    pub properties: Vec<Property>
+}
+
+impl Layout {
+    pub fn entry(properties: Vec<Property>) -> Entry {
+        Entry::Extension(Extension::Layout(Self { properties }))
+    }
+
+    /// Get pretty string description of this extension
+    pub fn dbg(&self) -> String {
+        format!(
+            "Layout {{\n  properties: [\n{}  ]\n}}",
+            self.properties.iter().map(|property| {
+                let text = property.dbg().lines()
+                    .map(|line| String::from("      ") + line + "\n")
+                    .collect::<String>();
+
+                text.trim_end().to_string() + ",\n"
+            }).collect::<String>()
+        )
+    }
+
+    /// Get XML description of this extension
+    pub fn get_xml(&self) -> String {
+        format!(
+            "<layout>{}</layout>",
+            self.properties.iter().map(|property| property.get_xml()).collect::<String>()
+        )
+    }
+}
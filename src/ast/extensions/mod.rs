@@ -0,0 +1,21 @@
+pub mod styles;
+pub mod layout;
+pub mod accessibility;
+pub mod items;
+pub mod attributes;
+pub mod size_group;
+pub mod file_filter;
+pub mod breakpoint;
+pub mod responses;
+
+pub mod prelude {
+    pub use super::styles::*;
+    pub use super::layout::*;
+    pub use super::accessibility::*;
+    pub use super::items::*;
+    pub use super::attributes::*;
+    pub use super::size_group::*;
+    pub use super::file_filter::*;
+    pub use super::breakpoint::*;
+    pub use super::responses::*;
+}
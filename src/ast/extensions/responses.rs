@@ -0,0 +1,102 @@
+use crate::ast::entry::Entry;
+use crate::ast::extension::Extension;
+use crate::ast::entries::property::StringValue;
+use crate::ast::xml::escape;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseAppearance {
+    Suggested,
+    Destructive
+}
+
+impl ResponseAppearance {
+    pub fn parse(keyword: &str) -> Option<Self> {
+        match keyword {
+            "suggested"   => Some(Self::Suggested),
+            "destructive" => Some(Self::Destructive),
+
+            _ => None
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Suggested   => "suggested",
+            Self::Destructive => "destructive"
+        }
+    }
+}
+
+/// Message dialog response
+///
+/// `delete: _("Delete") destructive disabled`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub id: String,
+    pub label: StringValue,
+    pub appearance: Option<ResponseAppearance>,
+    pub enabled: bool
+}
+
+impl Response {
+    /// Get pretty string description of this response
+    pub fn dbg(&self) -> String {
+        let mut text = format!("{}: {}", self.id, self.label.dbg());
+
+        if let Some(appearance) = &self.appearance {
+            text += " ";
+            text += appearance.as_str();
+        }
+
+        if !self.enabled {
+            text += " disabled";
+        }
+
+        text
+    }
+
+    /// Get XML description of this response
+    pub fn get_xml(&self) -> String {
+        let mut attributes = format!(" id=\"{}\"", escape(&self.id));
+
+        if let Some(appearance) = &self.appearance {
+            attributes += &format!(" appearance=\"{}\"", appearance.as_str());
+        }
+
+        if !self.enabled {
+            attributes += " enabled=\"false\"";
+        }
+
+        format!("<response{}{}>{}</response>", attributes, self.label.get_xml_attributes(), escape(self.label.get_text()))
+    }
+}
+
+/// Adw.MessageDialog and Adw.AlertDialog responses
+///
+/// `responses [cancel: _("Cancel"), delete: _("Delete") destructive]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Responses {
+    pub responses: Vec<Response>
+}
+
+impl Responses {
+    pub fn entry(responses: Vec<Response>) -> Entry {
+        Entry::Extension(Extension::Responses(Self { responses }))
+    }
+
+    /// Get pretty string description of this extension
+    pub fn dbg(&self) -> String {
+        format!(
+            "Responses [{}]",
+            self.responses.iter().map(|response| response.dbg()).collect::<Vec<String>>().join(", ")
+        )
+    }
+
+    /// Get XML description of this extension
+    pub fn get_xml(&self) -> String {
+        format!(
+            "<responses>{}</responses>",
+            self.responses.iter().map(|response| response.get_xml()).collect::<String>()
+        )
+    }
+}
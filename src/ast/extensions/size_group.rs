@@ -0,0 +1,29 @@
+use crate::ast::entry::Entry;
+use crate::ast::extension::Extension;
+
+/// Gtk.SizeGroup widgets
+///
+/// `widgets [label, entry]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Widgets {
+    pub widgets: Vec<String>
+}
+
+impl Widgets {
+    pub fn entry(widgets: Vec<String>) -> Entry {
+        Entry::Extension(Extension::Widgets(Self { widgets }))
+    }
+
+    /// Get pretty string description of this extension
+    pub fn dbg(&self) -> String {
+        format!("Widgets [{}]", self.widgets.join(", "))
+    }
+
+    /// Get XML description of this extension
+    pub fn get_xml(&self) -> String {
+        format!(
+            "<widgets>{}</widgets>",
+            self.widgets.iter().map(|widget| format!("<widget name=\"{}\"/>", widget)).collect::<String>()
+        )
+    }
+}
@@ -0,0 +1,32 @@
+use crate::ast::entry::Entry;
+use crate::ast::extension::Extension;
+use crate::ast::xml::escape;
+
+/// Object style classes
+///
+/// `styles ["card", "boxed-list"]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Styles {
+    pub classes: Vec<String>
+}
+
+impl Styles {
+    pub fn entry(classes: Vec<String>) -> Entry {
+        Entry::Extension(Extension::Styles(Self { classes }))
+    }
+
+    /// Get pretty string description of this extension
+    pub fn dbg(&self) -> String {
+        format!("Styles {:?}", self.classes)
+    }
+
+    /// Get XML description of this extension
+    pub fn get_xml(&self) -> String {
+        format!(
+            "<style>{}</style>",
+            self.classes.iter()
+                .map(|class| format!("<class name=\"{}\"/>", escape(class)))
+                .collect::<String>()
+        )
+    }
+}
@@ -1,9 +1,14 @@
 pub mod tree;
 pub mod entry;
 pub mod entries;
+pub mod extension;
+pub mod extensions;
+pub mod xml;
 
 pub mod prelude {
     pub use super::tree::*;
     pub use super::entry::*;
     pub use super::entries::prelude::*;
+    pub use super::extension::*;
+    pub use super::extensions::prelude::*;
 }
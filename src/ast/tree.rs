@@ -19,6 +19,10 @@ impl Tree {
         self.root.require(requirement);
     }
 
+    pub fn set_translation_domain<T: ToString>(&mut self, domain: T) {
+        self.root.set_translation_domain(domain);
+    }
+
     /// Get XML description of the tree
     pub fn get_xml(&self) -> String {
         self.root.get_xml()
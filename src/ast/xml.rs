@@ -0,0 +1,34 @@
+/// Escape special XML characters in the given text
+/// 
+/// ```
+/// use gtk_ui_builder::ast::xml::escape;
+/// 
+/// assert_eq!(escape("Tom & \"Jerry\""), "Tom &amp; &quot;Jerry&quot;");
+/// ```
+pub fn escape<T: ToString>(text: T) -> String {
+    let mut escaped = String::new();
+
+    for char in text.to_string().chars() {
+        match char {
+            '&'  => escaped += "&amp;",
+            '<'  => escaped += "&lt;",
+            '>'  => escaped += "&gt;",
+            '"'  => escaped += "&quot;",
+            '\'' => escaped += "&apos;",
+            _ => escaped.push(char)
+        }
+    }
+
+    escaped
+}
+
+/// Wrap the given text into the CDATA section
+/// 
+/// ```
+/// use gtk_ui_builder::ast::xml::cdata;
+/// 
+/// assert_eq!(cdata("<a>]]></a>"), "<![CDATA[<a>]]]]><![CDATA[></a>]]>");
+/// ```
+pub fn cdata<T: ToString>(text: T) -> String {
+    format!("<![CDATA[{}]]>", text.to_string().replace("]]>", "]]]]><![CDATA[>"))
+}
@@ -0,0 +1,284 @@
+use crate::ast::entries::expression::Expression;
+use crate::ast::entries::type_name::TypeName;
+use crate::ast::entries::property::PropertyValue;
+
+use super::parser::{Parser, Context};
+use super::tokenizer::Tokenizer;
+use super::token::{Token, Punctuation};
+use super::parse_error::ParseError;
+
+/// Parser of the GtkExpression syntax
+///
+/// `item as <Gtk.StringObject>.string` or `$closure_name(a, b) as <string>`
+#[derive(Default)]
+pub struct ExpressionParser {
+    /// Names of the objects used in the expression with their offsets
+    references: Vec<(String, usize)>,
+
+    /// Types used in the expression with their offsets
+    type_names: Vec<(TypeName, usize)>,
+
+    /// Bindings are not evaluated for an item, so `item` is an object name there
+    binding: bool
+}
+
+impl ExpressionParser {
+    /// Parse expression from the text. Errors offsets are relative to the text beginning
+    ///
+    /// ```
+    /// use gtk_ui_builder::parser::prelude::*;
+    /// use gtk_ui_builder::ast::prelude::*;
+    ///
+    /// let expression = ExpressionParser::parse("$format(item as <Gtk.StringObject>.string) as <string>").expect("Failed to parse expression");
+    ///
+    /// assert_eq!(expression.get_xml(), "<closure type=\"gchararray\" function=\"format\"><lookup name=\"string\" type=\"GtkStringObject\"/></closure>");
+    /// ```
+    pub fn parse<T: ToString>(text: T) -> Result<Expression, ParseError> {
+        let text = text.to_string();
+        let tokens = Tokenizer::parse(&text)?;
+
+        Self::default().parse_root(&tokens, text.chars().count())
+    }
+
+    /// Parse expression from the property value tokens following the `expr` keyword.
+    /// Used objects are added to the context references and types namespaces are checked
+    pub(super) fn parse_tokens(tokens: &[Token], context: &mut Context) -> Result<Expression, ParseError> {
+        let mut parser = Self::default();
+
+        let end = tokens.last().map(|token| token.get_end() + 1).unwrap_or_default();
+        let expression = parser.parse_root(tokens, end)?;
+
+        for (type_name, offset) in &parser.type_names {
+            context.check_namespace(type_name, *offset)?;
+        }
+
+        context.references.append(&mut parser.references);
+
+        Ok(expression)
+    }
+
+    /// Parse binding source from the tokens following the `bind` keyword.
+    /// Returns expression and the number of its tokens, the rest are binding flags
+    pub(super) fn parse_binding(tokens: &[Token], context: &mut Context) -> Result<(Expression, usize), ParseError> {
+        let mut parser = Self {
+            binding: true,
+            ..Self::default()
+        };
+
+        let end = tokens.last().map(|token| token.get_end() + 1).unwrap_or_default();
+        let (expression, length) = parser.parse_chain(tokens, end)?;
+
+        for (type_name, offset) in &parser.type_names {
+            context.check_namespace(type_name, *offset)?;
+        }
+
+        context.references.append(&mut parser.references);
+
+        Ok((expression, length))
+    }
+
+    /// Parse the whole expression. `end` is the offset reported if the tokens end unexpectedly
+    fn parse_root(&mut self, tokens: &[Token], end: usize) -> Result<Expression, ParseError> {
+        let expression = self.parse_expression(tokens, end)?;
+
+        // Bare `item` doesn't describe any value
+        if expression == Expression::Item {
+            return Err(Self::error("Expression must look up an item property", tokens[0].get_begin()));
+        }
+
+        Ok(expression)
+    }
+
+    /// Parse expression which takes all the tokens
+    fn parse_expression(&mut self, tokens: &[Token], end: usize) -> Result<Expression, ParseError> {
+        let (expression, length) = self.parse_chain(tokens, end)?;
+
+        match tokens.get(length) {
+            Some(token) => Err(Self::error("Unexpected token in expression", token.get_begin())),
+            None => Ok(expression)
+        }
+    }
+
+    /// Parse expression with its lookups from the beginning of the tokens.
+    /// Returns expression and the number of its tokens
+    fn parse_chain(&mut self, tokens: &[Token], end: usize) -> Result<(Expression, usize), ParseError> {
+        let (mut expression, mut i) = self.parse_primary(tokens, end)?;
+
+        while i < tokens.len() {
+            // Property lookup
+            //
+            // source.name
+            if tokens[i].is_punctuation(Punctuation::Dot) {
+                // GtkBuilder can't look up a property without knowing the item type
+                if expression == Expression::Item {
+                    return Err(Self::error("Item property lookup must specify the item type with `as <Type>`", tokens[i].get_begin()));
+                }
+
+                expression = Expression::Lookup {
+                    name: Self::parse_identifier(tokens, i + 1, end)?,
+                    type_name: None,
+                    source: Self::lookup_source(expression)
+                };
+
+                i += 2;
+            }
+
+            // Casted property lookup
+            //
+            // source as <Type>.name
+            else if tokens[i].is_identifier_value("as") {
+                let (type_name, length) = self.parse_type(tokens, i + 1, end)?;
+
+                i += 1 + length;
+
+                if !matches!(tokens.get(i), Some(token) if token.is_punctuation(Punctuation::Dot)) {
+                    return Err(Self::error("Type cast must be followed by a property lookup", Self::offset(tokens, i, end)));
+                }
+
+                expression = Expression::Lookup {
+                    name: Self::parse_identifier(tokens, i + 1, end)?,
+                    type_name: Some(type_name),
+                    source: Self::lookup_source(expression)
+                };
+
+                i += 2;
+            }
+
+            else {
+                break;
+            }
+        }
+
+        Ok((expression, i))
+    }
+
+    /// Parse expression from the beginning of the tokens.
+    /// Returns expression and the number of its tokens
+    fn parse_primary(&mut self, tokens: &[Token], end: usize) -> Result<(Expression, usize), ParseError> {
+        match tokens {
+            // (expression)
+            [Token::Parentheses { tokens: inner, end: close, .. }, ..] => {
+                Ok((self.parse_expression(inner, *close)?, 1))
+            }
+
+            // $closure_name(a, b) as <Type>
+            [dollar, Token::Identifier { value: function, .. }, Token::Parentheses { tokens: args, end: close, .. }, ..] if dollar.is_punctuation(Punctuation::Dollar) => {
+                let mut closure_args = Vec::new();
+
+                for arg in Parser::split_list(args) {
+                    let expression = self.parse_expression(&arg, *close)?;
+
+                    // There's no GtkBuilder expression for the item itself
+                    if expression == Expression::Item {
+                        return Err(Self::error("Closure argument must look up an item property", arg[0].get_begin()));
+                    }
+
+                    closure_args.push(expression);
+                }
+
+                if !matches!(tokens.get(3), Some(keyword) if keyword.is_identifier_value("as")) {
+                    return Err(Self::error("Closure return type must be specified with `as <type>`", Self::offset(tokens, 3, end)));
+                }
+
+                let (type_name, length) = self.parse_type(tokens, 4, end)?;
+
+                Ok((Expression::Closure {
+                    function: function.clone(),
+                    type_name,
+                    args: closure_args
+                }, 4 + length))
+            }
+
+            [dollar, ..] if dollar.is_punctuation(Punctuation::Dollar) => {
+                Err(Self::error("Closure arguments expected", Self::offset(tokens, 1, end)))
+            }
+
+            // "string" or 'string'
+            [Token::Text { value, .. }, ..] => Ok((Expression::Constant {
+                type_name: String::from("string"),
+                value: value.clone()
+            }, 1)),
+
+            // 12, -1.5, 0x1F
+            [Token::Number { value, begin, .. }, ..] => {
+                let constant = match Parser::parse_number(value, *begin)? {
+                    PropertyValue::Int(number) => ("int", number.to_string()),
+                    _ => ("double", value.replace('_', ""))
+                };
+
+                Ok((Expression::Constant {
+                    type_name: String::from(constant.0),
+                    value: constant.1
+                }, 1))
+            }
+
+            // item, true, my_object
+            [Token::Identifier { value, begin, .. }, ..] => Ok((match value.as_str() {
+                "item" if !self.binding => Expression::Item,
+
+                "true" | "false" => Expression::Constant {
+                    type_name: String::from("bool"),
+                    value: value.clone()
+                },
+
+                _ => {
+                    self.references.push((value.clone(), *begin));
+
+                    Expression::Object(value.clone())
+                }
+            }, 1)),
+
+            [token, ..] => Err(Self::error("Unexpected token in expression", token.get_begin())),
+
+            [] => Err(Self::error("Expression expected", end))
+        }
+    }
+
+    /// Parse `<Type>` starting from the `i` token.
+    /// Returns type name and the number of its tokens
+    fn parse_type(&mut self, tokens: &[Token], i: usize, end: usize) -> Result<(String, usize), ParseError> {
+        if !matches!(tokens.get(i), Some(token) if token.is_punctuation(Punctuation::LessThan)) {
+            return Err(Self::error("Type must be wrapped in angle brackets", Self::offset(tokens, i, end)));
+        }
+
+        match Parser::parse_type_name(&tokens[i + 1..]) {
+            Some((type_name, length)) if matches!(tokens.get(i + 1 + length), Some(token) if token.is_punctuation(Punctuation::GreaterThan)) => {
+                let name = type_name.to_string();
+
+                self.type_names.push((type_name, tokens[i + 1].get_begin()));
+
+                Ok((name, length + 2))
+            }
+
+            _ => Err(Self::error("Incorrect type name in expression", Self::offset(tokens, i + 1, end)))
+        }
+    }
+
+    fn parse_identifier(tokens: &[Token], i: usize, end: usize) -> Result<String, ParseError> {
+        match tokens.get(i) {
+            Some(Token::Identifier { value, .. }) => Ok(value.clone()),
+
+            _ => Err(Self::error("Identifier expected in expression", Self::offset(tokens, i, end)))
+        }
+    }
+
+    /// Lookups on the evaluated item have no source expression
+    fn lookup_source(expression: Expression) -> Option<Box<Expression>> {
+        match expression {
+            Expression::Item => None,
+            expression => Some(Box::new(expression))
+        }
+    }
+
+    /// Offset of the `i` token or `end` if there's no such token
+    fn offset(tokens: &[Token], i: usize, end: usize) -> usize {
+        tokens.get(i).map(|token| token.get_begin()).unwrap_or(end)
+    }
+
+    fn error(message: &str, offset: usize) -> ParseError {
+        ParseError::IncorrectExpression {
+            message: format!("{} at offset {}", message, offset),
+            offset
+        }
+    }
+}
@@ -0,0 +1,393 @@
+use crate::ast::entry::Entry;
+use crate::ast::extensions::prelude::*;
+use crate::ast::entries::property::{Property, PropertyValue, StringValue};
+
+use super::parser::{Parser, Context};
+use super::token::{Token, Punctuation};
+use super::parse_error::ParseError;
+
+impl Parser {
+    /// Check if tokens start with an object extension
+    /// 
+    /// `styles [...]` or `layout {...}`
+    pub(super) fn is_extension(tokens: &[Token]) -> bool {
+        match tokens {
+            [keyword, Token::Parentheses { .. }, ..] => keyword.is_identifier_value("condition"),
+
+            [keyword, Token::SquareBrackets { .. }, ..] => {
+                keyword.is_identifier_value("styles") ||
+                keyword.is_identifier_value("strings") ||
+                keyword.is_identifier_value("items") ||
+                keyword.is_identifier_value("widgets") ||
+                keyword.is_identifier_value("mime-types") ||
+                keyword.is_identifier_value("patterns") ||
+                keyword.is_identifier_value("suffixes") ||
+                keyword.is_identifier_value("responses")
+            }
+
+            [keyword, Token::CurlyBrackets { .. }, ..] => {
+                keyword.is_identifier_value("layout") ||
+                keyword.is_identifier_value("accessibility") ||
+                keyword.is_identifier_value("attributes") ||
+                keyword.is_identifier_value("setters")
+            }
+
+            _ => false
+        }
+    }
+
+    /// Parse object extension starting from its keyword.
+    /// Returns extension entry and the index of its last token
+    pub(super) fn parse_extension(text: &[char], tokens: &[Token], context: &mut Context) -> Result<(Entry, usize), ParseError> {
+        match &tokens[..2] {
+            // styles ["card", "boxed-list"]
+            [keyword, Token::SquareBrackets { tokens: items, .. }] if keyword.is_identifier_value("styles") => {
+                Ok((Styles::entry(Self::parse_strings_list(items)?), 1))
+            }
+
+            // strings ["a", _("b")]
+            [keyword, Token::SquareBrackets { tokens: items, begin, .. }] if keyword.is_identifier_value("strings") => {
+                Self::check_extension_class(keyword, &["Gtk.StringList"], context)?;
+
+                let mut strings = Vec::new();
+
+                for item in Self::split_list(items) {
+                    strings.push(Self::parse_string_value(&item, *begin)?);
+                }
+
+                Ok((Strings::entry(strings), 1))
+            }
+
+            // items [id: "label", _("Other label")]
+            [keyword, Token::SquareBrackets { tokens: items, begin, .. }] if keyword.is_identifier_value("items") => {
+                Self::check_extension_class(keyword, &["Gtk.ComboBoxText"], context)?;
+
+                let mut list = Vec::new();
+
+                for item in Self::split_list(items) {
+                    list.push(match item.as_slice() {
+                        [Token::Identifier { value: id, .. }, colon, value @ ..] if colon.is_punctuation(Punctuation::Colon) => Item {
+                            id: Some(id.clone()),
+                            value: Self::parse_string_value(value, *begin)?
+                        },
+
+                        value => Item {
+                            id: None,
+                            value: Self::parse_string_value(value, *begin)?
+                        }
+                    });
+                }
+
+                Ok((Items::entry(list), 1))
+            }
+
+            // widgets [label, entry]
+            [keyword, Token::SquareBrackets { tokens: items, begin, .. }] if keyword.is_identifier_value("widgets") => {
+                Self::check_extension_class(keyword, &["Gtk.SizeGroup"], context)?;
+
+                let mut widgets = Vec::new();
+
+                for item in Self::split_list(items) {
+                    match item.as_slice() {
+                        [Token::Identifier { value, begin, .. }] => {
+                            context.references.push((value.clone(), *begin));
+
+                            widgets.push(value.clone());
+                        }
+
+                        item => {
+                            let begin = item.first().map(|token| token.get_begin()).unwrap_or(*begin);
+
+                            return Err(ParseError::IncorrectExtensionDefinition {
+                                message: format!("Object name expected at offset {}", begin),
+                                offset: begin
+                            });
+                        }
+                    }
+                }
+
+                Ok((Widgets::entry(widgets), 1))
+            }
+
+            // responses [cancel: _("Cancel"), delete: _("Delete") destructive disabled]
+            [keyword, Token::SquareBrackets { tokens: items, begin, .. }] if keyword.is_identifier_value("responses") => {
+                Self::check_extension_class(keyword, &["Adw.MessageDialog", "Adw.AlertDialog"], context)?;
+
+                let mut responses = Vec::new();
+
+                for item in Self::split_list(items) {
+                    let item_begin = item.first().map(|token| token.get_begin()).unwrap_or(*begin);
+
+                    let (id, value) = match item.as_slice() {
+                        [Token::Identifier { value: id, .. }, colon, value @ ..] if colon.is_punctuation(Punctuation::Colon) && !value.is_empty() => {
+                            (id.clone(), value)
+                        }
+
+                        _ => return Err(ParseError::IncorrectExtensionDefinition {
+                            message: format!("Response must be in the id: \"label\" format, occured at offset {}", item_begin),
+                            offset: item_begin
+                        })
+                    };
+
+                    let label_length = if value.len() > 1 && Self::is_translated_string(&value[..2]) { 2 } else { 1 };
+
+                    let mut response = Response {
+                        id,
+                        label: Self::parse_string_value(&value[..label_length], *begin)?,
+                        appearance: None,
+                        enabled: true
+                    };
+
+                    for flag in value.iter().skip(label_length) {
+                        match flag {
+                            Token::Identifier { value, .. } if value == "disabled" && response.enabled => response.enabled = false,
+
+                            Token::Identifier { value, .. } if response.appearance.is_none() && ResponseAppearance::parse(value).is_some() => {
+                                response.appearance = ResponseAppearance::parse(value);
+                            }
+
+                            flag => return Err(ParseError::IncorrectExtensionDefinition {
+                                message: format!("Incorrect response flag at offset {}", flag.get_begin()),
+                                offset: flag.get_begin()
+                            })
+                        }
+                    }
+
+                    responses.push(response);
+                }
+
+                Ok((Responses::entry(responses), 1))
+            }
+
+            // mime-types ["image/png"], patterns ["*.txt"], suffixes ["png"]
+            [keyword @ Token::Identifier { value, .. }, Token::SquareBrackets { tokens: items, .. }] if FileFilterKind::parse(value).is_some() => {
+                Self::check_extension_class(keyword, &["Gtk.FileFilter"], context)?;
+
+                Ok((FileFilter::entry(FileFilterKind::parse(value).unwrap(), Self::parse_strings_list(items)?), 1))
+            }
+
+            // layout { column: 1; row: 2; }
+            [keyword, body @ Token::CurlyBrackets { .. }] if keyword.is_identifier_value("layout") => {
+                Ok((Layout::entry(Self::parse_properties_block(text, body, context)?), 1))
+            }
+
+            // accessibility { label: _("Close"); labelled-by: title; }
+            [keyword, body @ Token::CurlyBrackets { tokens: body_tokens, .. }] if keyword.is_identifier_value("accessibility") => {
+                let properties = Self::parse_properties_block(text, body, context)?;
+
+                for property in &properties {
+                    let (name_begin, value_begin) = Self::find_property_offsets(body, body_tokens, &property.name);
+
+                    match AccessibleKind::of(&property.name) {
+                        None => return Err(ParseError::IncorrectExtensionDefinition {
+                            message: format!("Unknown accessible property \"{}\" at offset {}", property.name, name_begin),
+                            offset: name_begin
+                        }),
+
+                        Some(AccessibleKind::Relation) if AccessibleKind::is_object_relation(&property.name) => {
+                            match &property.value {
+                                PropertyValue::Ident(object) => context.references.push((object.clone(), value_begin)),
+
+                                _ => return Err(ParseError::IncorrectExtensionDefinition {
+                                    message: format!("Relation \"{}\" must refer to an object, occured at offset {}", property.name, value_begin),
+                                    offset: value_begin
+                                })
+                            }
+                        }
+
+                        _ => ()
+                    }
+                }
+
+                Ok((Accessibility::entry(properties), 1))
+            }
+
+            // attributes { weight: bold; scale: 1.2; }
+            [keyword, body @ Token::CurlyBrackets { tokens: body_tokens, .. }] if keyword.is_identifier_value("attributes") => {
+                Self::check_extension_class(keyword, &["Gtk.Label"], context)?;
+
+                let mut attributes = Vec::new();
+
+                for property in Self::parse_properties_block(text, body, context)? {
+                    let (name_begin, value_begin) = Self::find_property_offsets(body, body_tokens, &property.name);
+
+                    if !PANGO_ATTRIBUTES.contains(&property.name.as_str()) {
+                        return Err(ParseError::IncorrectExtensionDefinition {
+                            message: format!("Unknown pango attribute \"{}\" at offset {}", property.name, name_begin),
+                            offset: name_begin
+                        });
+                    }
+
+                    match property.value.get_literal() {
+                        Some(value) => attributes.push(Attribute {
+                            name: property.name,
+                            value
+                        }),
+
+                        None => return Err(ParseError::IncorrectExtensionDefinition {
+                            message: format!("Pango attribute value must be a literal, occured at offset {}", value_begin),
+                            offset: value_begin
+                        })
+                    }
+                }
+
+                Ok((Attributes::entry(attributes), 1))
+            }
+
+            // condition ("max-width: 500sp")
+            [keyword, Token::Parentheses { tokens: args, begin, .. }] if keyword.is_identifier_value("condition") => {
+                Self::check_extension_class(keyword, &["Adw.Breakpoint"], context)?;
+
+                match args.as_slice() {
+                    [Token::Text { value, .. }] => Ok((Condition::entry(value), 1)),
+
+                    _ => Err(ParseError::IncorrectExtensionDefinition {
+                        message: format!("Breakpoint condition must be a string, occured at offset {}", begin),
+                        offset: *begin
+                    })
+                }
+            }
+
+            // setters { split_view.collapsed: true; }
+            [keyword, body @ Token::CurlyBrackets { tokens: body_tokens, .. }] if keyword.is_identifier_value("setters") => {
+                Self::check_extension_class(keyword, &["Adw.Breakpoint"], context)?;
+
+                let mut setters = Vec::new();
+
+                for property in Self::parse_properties_block(text, body, context)? {
+                    let (name_begin, value_begin) = Self::find_property_offsets(body, body_tokens, &property.name);
+
+                    let (object, name) = match property.name.split_once('.') {
+                        Some((object, name)) if !object.is_empty() && !name.is_empty() => (object.to_string(), name.to_string()),
+
+                        _ => return Err(ParseError::IncorrectExtensionDefinition {
+                            message: format!("Setter must be in the object.property format, occured at offset {}", name_begin),
+                            offset: name_begin
+                        })
+                    };
+
+                    let value = match property.value {
+                        PropertyValue::Translated(string) => StringValue::Translated(string),
+
+                        value => match value.get_literal() {
+                            Some(text) => StringValue::String(text),
+
+                            None => return Err(ParseError::IncorrectExtensionDefinition {
+                                message: format!("Setter value must be a literal, occured at offset {}", value_begin),
+                                offset: value_begin
+                            })
+                        }
+                    };
+
+                    context.references.push((object.clone(), name_begin));
+
+                    setters.push(Setter {
+                        object,
+                        property: name,
+                        value
+                    });
+                }
+
+                Ok((Setters::entry(setters), 1))
+            }
+
+            _ => unreachable!()
+        }
+    }
+
+    /// Find offsets of the property name and value in the block tokens
+    fn find_property_offsets(block: &Token, tokens: &[Token], name: &str) -> (usize, usize) {
+        for k in 0..tokens.len() {
+            match Self::parse_property_name(&tokens[k..]) {
+                Some((property, colon)) if property == name && k + colon + 1 < tokens.len() => {
+                    return (tokens[k].get_begin(), tokens[k + colon + 1].get_begin());
+                }
+
+                _ => ()
+            }
+        }
+
+        (block.get_begin(), block.get_begin())
+    }
+
+    /// Check that the extension is used inside of the object of supported class
+    fn check_extension_class(keyword: &Token, classes: &[&str], context: &Context) -> Result<(), ParseError> {
+        match context.classes.last() {
+            Some(class) if classes.contains(&class.as_str()) => Ok(()),
+
+            _ => Err(ParseError::IncorrectExtensionDefinition {
+                message: format!(
+                    "{} can only be used in {}, occured at offset {}",
+                    keyword.get_value().unwrap(),
+                    classes.join(", "),
+                    keyword.get_begin()
+                ),
+                offset: keyword.get_begin()
+            })
+        }
+    }
+
+    /// Parse string or translatable string list item
+    /// 
+    /// `"a"` or `_("a")`
+    fn parse_string_value(tokens: &[Token], list_begin: usize) -> Result<StringValue, ParseError> {
+        match tokens {
+            [Token::Text { value, .. }] => Ok(StringValue::String(value.clone())),
+
+            tokens if Self::is_translated_string(tokens) => Ok(StringValue::Translated(Self::parse_translated_string(tokens)?)),
+
+            tokens => {
+                let offset = tokens.first().map(|token| token.get_begin()).unwrap_or(list_begin);
+
+                Err(ParseError::IncorrectExtensionDefinition {
+                    message: format!("String expected at offset {}", offset),
+                    offset
+                })
+            }
+        }
+    }
+
+    /// Parse block which can only contain properties
+    /// 
+    /// `{ column: 1; row: 2; }`
+    fn parse_properties_block(text: &[char], token: &Token, context: &mut Context) -> Result<Vec<Property>, ParseError> {
+        let mut properties = Vec::new();
+
+        for entry in Self::parse_block(text, token, context)? {
+            match entry {
+                Entry::Property(property) => properties.push(property),
+
+                _ => return Err(ParseError::IncorrectExtensionDefinition {
+                    message: format!("Block can only contain properties, occured at offset {}", token.get_begin()),
+                    offset: token.get_begin()
+                })
+            }
+        }
+
+        Ok(properties)
+    }
+
+    /// Parse list of plain strings
+    /// 
+    /// `"a", "b"`
+    fn parse_strings_list(tokens: &[Token]) -> Result<Vec<String>, ParseError> {
+        let mut strings = Vec::new();
+
+        for item in Self::split_list(tokens) {
+            match item.as_slice() {
+                [Token::Text { value, .. }] => strings.push(value.clone()),
+
+                item => {
+                    let offset = item.first().map(|token| token.get_begin()).unwrap_or_default();
+
+                    return Err(ParseError::IncorrectExtensionDefinition {
+                        message: format!("String expected at offset {}", offset),
+                        offset
+                    });
+                }
+            }
+        }
+
+        Ok(strings)
+    }
+}
@@ -2,12 +2,17 @@ pub mod token;
 pub mod tokenize_error;
 pub mod parse_error;
 pub mod tokenizer;
+pub mod expression;
+#[allow(clippy::module_inception)]
 pub mod parser;
 
+mod extensions;
+
 pub mod prelude {
     pub use super::token::*;
     pub use super::tokenize_error::*;
     pub use super::parse_error::*;
     pub use super::tokenizer::*;
+    pub use super::expression::*;
     pub use super::parser::*;
 }
@@ -1,4 +1,4 @@
-use std::io::{Error, ErrorKind};
+use std::io::Error;
 
 use super::tokenize_error::TokenizeError;
 
@@ -9,6 +9,10 @@ pub enum ParseError {
         message: String,
         offset: usize
     },
+    IncorrectTranslationDomain {
+        message: String,
+        offset: usize
+    },
     IncorrectObjectDefinition {
         message: String,
         offset: usize
@@ -24,19 +28,45 @@ pub enum ParseError {
     IncorrectEventDefinition {
         message: String,
         offset: usize
+    },
+    IncorrectExpression {
+        message: String,
+        offset: usize
+    },
+    IncorrectMenuDefinition {
+        message: String,
+        offset: usize
+    },
+    IncorrectExtensionDefinition {
+        message: String,
+        offset: usize
+    },
+    UndefinedObject {
+        message: String,
+        offset: usize
+    },
+    UndefinedNamespace {
+        message: String,
+        offset: usize
     }
 }
 
 impl ParseError {
     pub fn get_message(&self) -> &str {
         match self {
-            Self::TokenizeError(err) => &err.get_message(),
+            Self::TokenizeError(err) => err.get_message(),
 
             Self::IncorrectUseStatement { message, .. } |
+            Self::IncorrectTranslationDomain { message, .. } |
             Self::IncorrectObjectDefinition { message, .. } |
             Self::IncorrectPropertyDefinition { message, .. } |
             Self::IncorrectSyntax { message, .. } |
-            Self::IncorrectEventDefinition { message, .. } => message.as_str()
+            Self::IncorrectEventDefinition { message, .. } |
+            Self::IncorrectExpression { message, .. } |
+            Self::IncorrectMenuDefinition { message, .. } |
+            Self::IncorrectExtensionDefinition { message, .. } |
+            Self::UndefinedObject { message, .. } |
+            Self::UndefinedNamespace { message, .. } => message.as_str()
         }
     }
 
@@ -45,19 +75,25 @@ impl ParseError {
             Self::TokenizeError(err) => return Self::TokenizeError(err.clone().offset(num)),
 
             Self::IncorrectUseStatement { offset, .. } |
+            Self::IncorrectTranslationDomain { offset, .. } |
             Self::IncorrectObjectDefinition { offset, .. } |
             Self::IncorrectPropertyDefinition { offset, .. } |
             Self::IncorrectSyntax { offset, .. } |
-            Self::IncorrectEventDefinition { offset, .. } => *offset += num
+            Self::IncorrectEventDefinition { offset, .. } |
+            Self::IncorrectExpression { offset, .. } |
+            Self::IncorrectMenuDefinition { offset, .. } |
+            Self::IncorrectExtensionDefinition { offset, .. } |
+            Self::UndefinedObject { offset, .. } |
+            Self::UndefinedNamespace { offset, .. } => *offset += num
         }
 
         self
     }
 }
 
-impl Into<Error> for ParseError {
-    fn into(self) -> Error {
-        Error::new(ErrorKind::Other, self.get_message())
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::other(err.get_message())
     }
 }
 
@@ -1,211 +1,509 @@
+use std::num::IntErrorKind;
+
 use crate::ast::tree::Tree;
 use crate::ast::entries::prelude::*;
 use crate::ast::entry::Entry;
+use crate::ast::extension::Extension;
 
 use super::tokenizer::Tokenizer;
-use super::token::Token;
+use super::expression::ExpressionParser;
+use super::token::{Token, Punctuation};
 use super::parse_error::ParseError;
 
 pub struct Parser;
 
+/// State shared between all the nested blueprint parts
+#[derive(Default)]
+pub(super) struct Context {
+    /// Names of the referenced objects with their offsets
+    pub references: Vec<(String, usize)>,
+
+    /// Classes of the objects the currently parsed part is nested in
+    pub classes: Vec<String>,
+
+    /// Number of menus the currently parsed part is nested in
+    pub menu_depth: usize,
+
+    /// Number of generated ids
+    pub generated_ids: usize,
+
+    /// Namespaces imported by the use statements
+    pub namespaces: Vec<String>,
+
+    /// Objects referenced from the nested list item templates
+    /// which are not defined there, with their offsets
+    pub nested_references: Vec<(String, usize)>
+}
+
+impl Context {
+    /// Generate id for an anonymous entry which must be referenced
+    pub fn generate_id(&mut self, prefix: &str) -> String {
+        self.generated_ids += 1;
+
+        format!("__{}_{}", prefix, self.generated_ids)
+    }
+
+    /// Check that the type's namespace was imported by a use statement.
+    /// Gtk is always available since every interface is built by it
+    pub fn check_namespace(&self, type_name: &TypeName, offset: usize) -> Result<(), ParseError> {
+        match &type_name.namespace {
+            Some(namespace) if namespace != "Gtk" && !self.namespaces.contains(namespace) => {
+                Err(ParseError::UndefinedNamespace {
+                    message: format!("Namespace \"{}\" is not imported by a use statement (only Gtk is available without it), occured at offset {}", namespace, offset),
+                    offset
+                })
+            }
+
+            _ => Ok(())
+        }
+    }
+}
+
 impl Parser {
     pub fn parse<T: ToString>(text: T) -> Result<Tree, ParseError> {
-        let mut tree = Tree::new();
-        let text = text.to_string();
+        let mut context = Context::default();
 
+        let text = text.to_string();
         let tokens = Tokenizer::parse(&text)?;
+
+        let mut tree = Self::parse_entries(&text.chars().collect::<Vec<char>>(), &tokens, &mut context)?;
+
+        let names = Self::resolve_references(&mut tree.root, &context.references)?;
+
+        Self::check_nested_references(&names, &context.nested_references)?;
+
+        Ok(tree)
+    }
+
+    /// Check that all the referenced objects are defined in the interface
+    /// and turn identifiers which are their names into object references.
+    /// Returns names of the interface objects
+    fn resolve_references(root: &mut Root, references: &[(String, usize)]) -> Result<Vec<String>, ParseError> {
+        let names = root.get_named_objects().into_iter().map(|(name, _)| name).collect::<Vec<String>>();
+
+        for (name, offset) in references {
+            if !names.contains(name) {
+                return Err(ParseError::UndefinedObject {
+                    message: format!("Undefined object \"{}\" referenced at offset {}", name, offset),
+                    offset: *offset
+                });
+            }
+        }
+
+        Self::resolve_object_refs(&mut root.children, &names);
+
+        Ok(names)
+    }
+
+    /// List item templates are built as separate interfaces,
+    /// so their references to the outer objects are undefined as well
+    fn check_nested_references(names: &[String], references: &[(String, usize)]) -> Result<(), ParseError> {
+        match references.first() {
+            Some((name, offset)) if names.contains(name) => Err(ParseError::UndefinedObject {
+                message: format!("Object \"{}\" is not accessible from the list item template, occured at offset {}", name, offset),
+                offset: *offset
+            }),
+
+            Some((name, offset)) => Err(ParseError::UndefinedObject {
+                message: format!("Undefined object \"{}\" referenced at offset {}", name, offset),
+                offset: *offset
+            }),
+
+            None => Ok(())
+        }
+    }
+
+    /// Turn identifiers which are names of the blueprint objects into object references
+    fn resolve_object_refs(entries: &mut [Entry], names: &[String]) {
+        fn resolve_value(value: &mut PropertyValue, names: &[String]) {
+            match value {
+                PropertyValue::Ident(name) if names.contains(name) => *value = PropertyValue::ObjectRef(name.clone()),
+                PropertyValue::Entry(obj) => Parser::resolve_object_refs(&mut obj.children, names),
+
+                _ => ()
+            }
+        }
+
+        for entry in entries {
+            match entry {
+                Entry::Object(obj) => Self::resolve_object_refs(&mut obj.children, names),
+                Entry::Template(template) => Self::resolve_object_refs(&mut template.children, names),
+                Entry::Property(property) => resolve_value(&mut property.value, names),
+
+                Entry::Extension(Extension::Accessibility(accessibility)) => {
+                    for property in &mut accessibility.properties {
+                        resolve_value(&mut property.value, names);
+                    }
+                }
+
+                _ => ()
+            }
+        }
+    }
+
+    /// Parse blueprint part from its tokens.
+    /// Tokens offsets are char indexes of the whole blueprint `text`
+    fn parse_entries(text: &[char], tokens: &[Token], context: &mut Context) -> Result<Tree, ParseError> {
+        let mut tree = Tree::new();
+
         let mut i = 0;
 
+        // Child type annotation of the next object with its offset
+        let mut child_type: Option<(ChildType, usize)> = None;
+
         while i < tokens.len() {
+            let annotated = child_type.is_some();
+
             // Use statements
             // 
-            // using Adw 1.0
-            if tokens[i].is_other_value("using") {
-                if i + 2 < tokens.len() && tokens[i + 1].is_other() && tokens[i + 2].is_other() {
-                    tree.require(Requirement::new(
-                        tokens[i + 1].get_value().unwrap(),
-                        tokens[i + 2].get_value().unwrap()
-                    ));
+            // using Adw 1.0;
+            if tokens[i].is_identifier_value("using") {
+                let begin = tokens[i].get_begin();
 
-                    i += 2;
+                if !tree.root.children.is_empty() || !context.classes.is_empty() || context.menu_depth > 0 {
+                    return Err(ParseError::IncorrectUseStatement {
+                        message: format!("Use statements must be placed before other definitions, occured at offset {}", begin),
+                        offset: begin
+                    });
                 }
 
-                else {
+                let requirement = match &tokens[i + 1..] {
+                    [Token::Identifier { value: namespace, .. }, Token::Number { value: version, begin, .. }, ..] => {
+                        match Version::parse(version) {
+                            Some(version) => Requirement::new(namespace, version),
+                            None => return Err(ParseError::IncorrectUseStatement {
+                                message: format!("Incorrect version \"{}\" at offset {}", version, begin),
+                                offset: *begin
+                            })
+                        }
+                    }
+
+                    _ => return Err(ParseError::IncorrectUseStatement {
+                        message: format!("Incorrect use statement at offset {}", begin),
+                        offset: begin
+                    })
+                };
+
+                // Version must be a single number token, `4.0` and not `4.a`
+                if let Some(token @ Token::Punctuation { value, .. }) = tokens.get(i + 3) {
+                    if *value != Punctuation::Semicolon {
+                        return Err(ParseError::IncorrectUseStatement {
+                            message: format!("Incorrect version at offset {}", token.get_begin()),
+                            offset: token.get_begin()
+                        });
+                    }
+                }
+
+                let namespace = requirement.get_namespace().to_string();
+
+                if let Some(imported) = tree.root.requirements.iter().find(|imported| imported.get_namespace() == namespace) {
+                    let message = if imported.get_version() == requirement.get_version() {
+                        format!("Duplicate use statement of {} at offset {}", namespace, begin)
+                    } else {
+                        format!("Conflicting use statement of {} {} at offset {}, version {} is already used", namespace, requirement.get_version(), begin, imported.get_version())
+                    };
+
                     return Err(ParseError::IncorrectUseStatement {
-                        message: format!("Incorrect use statement at offset {}", tokens[i].get_begin()),
-                        offset: tokens[i].get_begin()
+                        message,
+                        offset: begin
                     });
                 }
-            }
 
-            // Components, events or properties definitions
-            else if let Token::Other { value, .. } = &tokens[i] {
-                let class = value.clone();
+                context.namespaces.push(namespace);
 
-                // Property definition
-                // 
-                // property-name: property-value;
-                if class.chars().last() == Some(':') {
-                    if i + 1 < tokens.len() {
-                        let mut j = i + 1;
-                        let mut correct_ending = false;
+                tree.require(requirement);
 
-                        while j < tokens.len() {
-                            if let Token::Other { value, .. } = &tokens[j] {
-                                if value.chars().last() == Some(';') {
-                                    correct_ending = true;
+                i += 2;
 
-                                    break;
-                                }
-                            }
+                if matches!(tokens.get(i + 1), Some(token) if token.is_punctuation(Punctuation::Semicolon)) {
+                    i += 1;
+                }
+            }
 
-                            j += 1;
-                        }
+            // Translation domain
+            // 
+            // translation-domain "myapp";
+            else if tokens[i].is_identifier_value("translation-domain") {
+                let begin = tokens[i].get_begin();
 
-                        if !correct_ending {
-                            return Err(ParseError::IncorrectPropertyDefinition {
-                                message: format!("Property value must be ended by semicolon, occured at offset {}", tokens[i].get_begin()),
-                                offset: tokens[i].get_begin()
-                            });
-                        }
+                if !tree.root.children.is_empty() || !context.classes.is_empty() || context.menu_depth > 0 {
+                    return Err(ParseError::IncorrectTranslationDomain {
+                        message: format!("Translation domain must be placed before other definitions, occured at offset {}", begin),
+                        offset: begin
+                    });
+                }
 
-                        tree.add_child(Property::entry(class[..class.len() - 1].to_string(), {
-                            if j - i <= 2 {
-                                // [Text(..)] [Other(;)]  Example: "Hi";
-                                if j - i == 2 {
-                                    // TODO brackets support
-                                    PropertyValue::Text(tokens[i + 1].get_value().unwrap())
-                                }
-
-                                // [Text(..;)] Example: Hi;
-                                else {
-                                    // TODO brackets support
-                                    let text = tokens[i + 1].get_value().unwrap();
-                                    
-                                    PropertyValue::Text(text[..text.len() - 1].to_string())
-                                }
-                            } else {
-                                let children = match Parser::parse(&text[tokens[i + 1].get_begin()..tokens[j].get_end()]) {
-                                    Ok(tree) => tree,
-                                    Err(err) => return Err(err.offset(tokens[i + 1].get_begin()))
-                                };
-
-                                let children = children.root.children;
-
-                                if children.len() != 1 {
-                                    return Err(ParseError::IncorrectPropertyDefinition {
-                                        message: format!("Property value must be a single object, occured at offset {}", tokens[i].get_begin()),
-                                        offset: tokens[i].get_begin()
-                                    });
-                                }
-
-                                else {
-                                    match &children[0] {
-                                        Entry::Object(obj) => PropertyValue::Entry(obj.clone()),
-                                        _ => {
-                                            return Err(ParseError::IncorrectPropertyDefinition {
-                                                message: format!("Property value must be an object, occured at offset {}", tokens[i].get_begin()),
-                                                offset: tokens[i].get_begin()
-                                            });
-                                        }
-                                    }
-                                }
-                            }
-                        }));
-
-                        i = j;
-                    }
+                if tree.root.translation_domain.is_some() {
+                    return Err(ParseError::IncorrectTranslationDomain {
+                        message: format!("Translation domain is already set, occured at offset {}", begin),
+                        offset: begin
+                    });
+                }
 
-                    else {
-                        return Err(ParseError::IncorrectPropertyDefinition {
-                            message: format!("Incorrect property definition at offset {}", tokens[i].get_begin()),
-                            offset: tokens[i].get_begin()
-                        });
+                match &tokens[i + 1..] {
+                    [Token::Text { value, .. }, semicolon, ..] if semicolon.is_punctuation(Punctuation::Semicolon) => {
+                        tree.set_translation_domain(value);
+
+                        i += 2;
                     }
+
+                    _ => return Err(ParseError::IncorrectTranslationDomain {
+                        message: format!("Translation domain must be a string ended by semicolon, occured at offset {}", begin),
+                        offset: begin
+                    })
                 }
+            }
+
+            // Property definition
+            // 
+            // property-name: property-value;
+            else if let Some((name, colon)) = Self::parse_property_name(&tokens[i..]) {
+                let colon = i + colon;
 
-                // Component event connection
-                // 
-                // clicked => { some code }
-                else if i + 1 < tokens.len() && tokens[i + 1].is_other_value("=>") {
-                    if i + 2 < tokens.len() {
-                        if let Token::CurlyBrackets { begin, end, .. } = tokens[i + 2] {
-                            // TODO: lazy brackets parsing. This will fix events definition errors
+                let end = match tokens[colon + 1..].iter().position(|token| token.is_punctuation(Punctuation::Semicolon)) {
+                    Some(end) => colon + 1 + end,
+                    None => return Err(ParseError::IncorrectPropertyDefinition {
+                        message: format!("Property value must be ended by semicolon, occured at offset {}", tokens[i].get_begin()),
+                        offset: tokens[i].get_begin()
+                    })
+                };
 
-                            if cfg!(feature = "rhai-events") {
-                                #[cfg(feature = "rhai-events")]
-                                tree.add_child(RhaiEvent::entry(class, text[begin + 1..end].to_string()));
+                if end == colon + 1 {
+                    return Err(ParseError::IncorrectPropertyDefinition {
+                        message: format!("Incorrect property definition at offset {}", tokens[i].get_begin()),
+                        offset: tokens[i].get_begin()
+                    });
+                }
+
+                tree.add_child(Property::entry(
+                    name,
+                    Self::parse_property_value(text, &tokens[colon], &tokens[colon + 1..end], context)?
+                ));
 
-                                i += 2;
-                            }
+                i = end;
+            }
 
-                            else {
-                                return Err(ParseError::IncorrectEventDefinition {
-                                    message: format!("Rhai feature is not enabled, occured at offset {}", tokens[i].get_begin()),
-                                    offset: tokens[i].get_begin()
-                                });
-                            }
+            // Component event connection
+            // 
+            // clicked => { some code }
+            else if let Some((name, arrow)) = Self::parse_signal_name(&tokens[i..]) {
+                let arrow = i + arrow;
+
+                match tokens.get(arrow + 1) {
+                    Some(Token::CurlyBrackets { .. }) => {
+                        if cfg!(feature = "rhai-events") {
+                            #[cfg(feature = "rhai-events")]
+                            tree.add_child(RhaiEvent::entry(name, text[tokens[arrow + 1].get_begin() + 1..tokens[arrow + 1].get_end()].iter().collect::<String>()));
+
+                            i = arrow + 1;
                         }
 
                         else {
                             return Err(ParseError::IncorrectEventDefinition {
-                                message: format!("Undefined event value at offset {}", tokens[i].get_begin()),
+                                message: format!("Rhai feature is not enabled, occured at offset {}", tokens[i].get_begin()),
                                 offset: tokens[i].get_begin()
                             });
                         }
                     }
 
-                    else {
-                        return Err(ParseError::IncorrectEventDefinition {
-                            message: format!("Incorrect event definition at offset {}", tokens[i].get_begin()),
-                            offset: tokens[i].get_begin()
-                        });
+                    // clicked => $on_clicked() swapped after;
+                    Some(token) if token.is_punctuation(Punctuation::Dollar) => {
+                        let end = match tokens[arrow + 1..].iter().position(|token| token.is_punctuation(Punctuation::Semicolon)) {
+                            Some(end) => arrow + 1 + end,
+                            None => return Err(ParseError::IncorrectEventDefinition {
+                                message: format!("Signal handler must be ended by semicolon, occured at offset {}", tokens[i].get_begin()),
+                                offset: tokens[i].get_begin()
+                            })
+                        };
+
+                        let signal = Self::parse_signal(name, &tokens[arrow + 1..end], context)?;
+
+                        tree.add_child(signal);
+
+                        i = end;
+                    }
+
+                    Some(_) => return Err(ParseError::IncorrectEventDefinition {
+                        message: format!("Undefined event value at offset {}", tokens[i].get_begin()),
+                        offset: tokens[i].get_begin()
+                    }),
+
+                    None => return Err(ParseError::IncorrectEventDefinition {
+                        message: format!("Incorrect event definition at offset {}", tokens[i].get_begin()),
+                        offset: tokens[i].get_begin()
+                    })
+                }
+            }
+
+            // Composite template definition
+            // 
+            // template $ClassName : Parent.Type {}
+            else if tokens[i].is_identifier_value("template") {
+                let factory = matches!(context.classes.last(), Some(class) if class == "Gtk.BuilderListItemFactory");
+
+                // Only one template can be defined, either at the root or as the list item template
+                if (!factory && (!context.classes.is_empty() || context.menu_depth > 0)) || tree.root.children.iter().any(|child| matches!(child, Entry::Template(_))) {
+                    return Err(ParseError::IncorrectObjectDefinition {
+                        message: format!("Incorrect template placement at offset {}", tokens[i].get_begin()),
+                        offset: tokens[i].get_begin()
+                    });
+                }
+
+                let (template, j) = if factory {
+                    Self::parse_list_item_template(text, &tokens[i..], context)?
+                } else {
+                    Self::parse_template(text, &tokens[i..], context)?
+                };
+
+                tree.add_child(Template::entry(template.class, template.parent, template.children));
+
+                i += j;
+            }
+
+            // Menu definition
+            // 
+            // menu id { section { item { label: _("Quit"); action: "app.quit"; } } }
+            // 
+            // item (_("Quit"), "app.quit", "icon")
+            else if let Some(kind) = Self::parse_menu_kind(&tokens[i]) {
+                if (kind == MenuKind::Menu) != (context.menu_depth == 0) {
+                    return Err(ParseError::IncorrectMenuDefinition {
+                        message: format!("Incorrect {} placement at offset {}", kind.as_str(), tokens[i].get_begin()),
+                        offset: tokens[i].get_begin()
+                    });
+                }
+
+                let (menu, j) = Self::parse_menu(kind, text, &tokens[i..], context)?;
+
+                tree.add_child(Menu::entry(menu.kind, menu.id, menu.children));
+
+                i += j;
+            }
+
+            // Object extensions
+            // 
+            // styles ["card", "boxed-list"]
+            else if Self::is_extension(&tokens[i..]) {
+                if context.classes.is_empty() {
+                    return Err(ParseError::IncorrectExtensionDefinition {
+                        message: format!("Extensions can only be defined inside of objects, occured at offset {}", tokens[i].get_begin()),
+                        offset: tokens[i].get_begin()
+                    });
+                }
+
+                let (extension, j) = Self::parse_extension(text, &tokens[i..], context)?;
+
+                tree.add_child(extension);
+
+                i += j;
+            }
+
+            // Optional semicolon after the block
+            // 
+            // Gtk.Box {};
+            else if i > 0 && tokens[i].is_punctuation(Punctuation::Semicolon) && matches!(tokens[i - 1], Token::CurlyBrackets { .. } | Token::Parentheses { .. }) {
+                // Nothing to parse
+            }
+
+            // Components definitions
+            // 
+            // Adw.ApplicationWindow {}
+            // 
+            // Adw.ApplicationWindow object_name {}
+            else if matches!(tokens[i], Token::Identifier { .. } | Token::Number { .. } | Token::Punctuation { .. }) {
+                let mut name = None;
+
+                // Gtk.Box or $MyAppSidebar
+                let class = match Self::parse_class_name(&tokens[i..]) {
+                    Some((class, length)) => {
+                        context.check_namespace(&class, tokens[i].get_begin())?;
+
+                        i += length - 1;
+
+                        class
                     }
+
+                    None => return Err(ParseError::IncorrectObjectDefinition {
+                        message: format!("Incorrect object type name at offset {}", tokens[i].get_begin()),
+                        offset: tokens[i].get_begin()
+                    })
+                };
+
+                if let Some(Token::Identifier { value, .. }) = tokens.get(i + 1) {
+                    name = Some(value.clone());
+
+                    i += 1;
                 }
 
-                // Components definitions
-                // 
                 // Adw.ApplicationWindow {}
-                // 
-                // Adw.ApplicationWindow object_name {}
-                else {
-                    let mut name = None;
+                if let Some(body @ Token::CurlyBrackets { .. }) = tokens.get(i + 1) {
+                    context.classes.push(class.to_string());
+
+                    let children = Self::parse_block(text, body, context);
 
-                    // Adw.ApplicationWindow
-                    if i + 1 >= tokens.len() {
-                        return Err(ParseError::IncorrectObjectDefinition {
-                            message: format!("Incorrect object definition at offset {}", tokens[i].get_begin()),
-                            offset: tokens[i].get_begin()
+                    context.classes.pop();
+
+                    let children = children?;
+
+                    if children.iter().any(|child| matches!(child, Entry::Menu(_))) {
+                        return Err(ParseError::IncorrectMenuDefinition {
+                            message: format!("Menus can't be objects children, occured at offset {}", body.get_begin()),
+                            offset: body.get_begin()
                         });
                     }
 
-                    if let Token::Other { value, .. } = &tokens[i + 1] {
-                        name = Some(value.clone());
+                    // List item template is stored as a separate interface
+                    // 
+                    // Gtk.BuilderListItemFactory { template ListItem {} }
+                    let children = if class.to_string() == "Gtk.BuilderListItemFactory" {
+                        children.into_iter().map(|child| match child {
+                            Entry::Template(template) => Property::entry(String::from("bytes"), PropertyValue::Template(template.into_interface())),
+                            child => child
+                        }).collect()
+                    } else {
+                        children
+                    };
 
-                        i += 1;
-                    }
+                    let child_type = child_type.take().map(|(child_type, _)| child_type);
 
-                    // Adw.ApplicationWindow {}
-                    if i + 1 < tokens.len() {
-                        tree.add_child(Object::entry(class, name, {
-                            if tokens[i + 1].get_end() - tokens[i + 1].get_begin() > 0 {
-                                Self::parse(&text[tokens[i + 1].get_begin() + 1..tokens[i + 1].get_end()])?.root.children
-                            } else {
-                                Vec::new()
-                            }
-                        }));
-
-                        i += 1;
+                    // Action widgets are referenced by their ids
+                    if let (Some(ChildType::Action { .. }), None) = (&child_type, &name) {
+                        name = Some(context.generate_id("action_widget"));
                     }
 
-                    // Adw.ApplicationWindow obj_name
-                    else {
-                        return Err(ParseError::IncorrectObjectDefinition {
-                            message: format!("Incorrect object definition at offset {}", tokens[i].get_begin()),
-                            offset: tokens[i].get_begin()
-                        });
-                    }
+                    tree.add_child(Entry::Object(Object {
+                        class,
+                        name,
+                        child_type,
+                        children
+                    }));
+
+                    i += 1;
+                }
+
+                // Adw.ApplicationWindow obj_name
+                else {
+                    return Err(ParseError::IncorrectObjectDefinition {
+                        message: format!("Incorrect object definition at offset {}", tokens[i].get_begin()),
+                        offset: tokens[i].get_begin()
+                    });
+                }
+            }
+
+            // Child type annotation
+            // 
+            // [titlebar] Adw.HeaderBar {}
+            // 
+            // [internal-child content_area] Gtk.Box {}
+            else if let Token::SquareBrackets { tokens: annotation, begin, .. } = &tokens[i] {
+                if annotated || context.classes.is_empty() {
+                    return Err(ParseError::IncorrectObjectDefinition {
+                        message: format!("Incorrect child type annotation at offset {}", begin),
+                        offset: *begin
+                    });
                 }
+
+                child_type = Some((Self::parse_child_type(annotation, *begin)?, *begin));
             }
 
             // Random brackets or string in the middle of markup
@@ -216,9 +514,783 @@ impl Parser {
                 });
             }
 
+            // Annotation wasn't used by an object
+            if let (true, Some((_, begin))) = (annotated, &child_type) {
+                return Err(ParseError::IncorrectObjectDefinition {
+                    message: format!("Child type annotation must be followed by an object, occured at offset {}", begin),
+                    offset: *begin
+                });
+            }
+
             i += 1;
         }
 
+        if let Some((_, begin)) = child_type {
+            return Err(ParseError::IncorrectObjectDefinition {
+                message: format!("Child type annotation must be followed by an object, occured at offset {}", begin),
+                offset: begin
+            });
+        }
+
         Ok(tree)
     }
+
+    /// Parse entries inside of the brackets token
+    pub(super) fn parse_block(text: &[char], token: &Token, context: &mut Context) -> Result<Vec<Entry>, ParseError> {
+        match token {
+            Token::CurlyBrackets { tokens, .. } => Ok(Self::parse_entries(text, tokens, context)?.root.children),
+
+            _ => Ok(Vec::new())
+        }
+    }
+
+    /// Parse type name from the beginning of the tokens.
+    /// Returns type name and the number of its tokens
+    /// 
+    /// `Gtk.Box` or `$MyAppSidebar`
+    pub(super) fn parse_type_name(tokens: &[Token]) -> Option<(TypeName, usize)> {
+        let mut name = String::new();
+        let mut j = 0;
+
+        if tokens.first()?.is_punctuation(Punctuation::Dollar) {
+            name.push('$');
+
+            j += 1;
+        }
+
+        match tokens.get(j) {
+            Some(Token::Identifier { value, .. }) => name += value,
+            _ => return None
+        }
+
+        j += 1;
+
+        while let [dot, Token::Identifier { value, .. }, ..] = &tokens[j..] {
+            if !dot.is_punctuation(Punctuation::Dot) {
+                break;
+            }
+
+            name.push('.');
+            name += value;
+
+            j += 2;
+        }
+
+        TypeName::parse(&name).map(|type_name| (type_name, j))
+    }
+
+    /// Parse class name from the beginning of the tokens.
+    /// Returns class name and the number of its tokens
+    /// 
+    /// `Gtk.Box`, `$MyAppSidebar` or `Box` which is a shortcut for `Gtk.Box`
+    fn parse_class_name(tokens: &[Token]) -> Option<(TypeName, usize)> {
+        let (mut class, length) = Self::parse_type_name(tokens)?;
+
+        if class.namespace.is_none() && !class.external {
+            class.namespace = Some(String::from("Gtk"));
+        }
+
+        Some((class, length))
+    }
+
+    /// Parse property name if the tokens start with a property definition.
+    /// Returns property name and the index of its colon
+    /// 
+    /// `label:` or `split_view.collapsed:`
+    pub(super) fn parse_property_name(tokens: &[Token]) -> Option<(String, usize)> {
+        let mut name = match tokens.first() {
+            Some(Token::Identifier { value, .. }) => value.clone(),
+            _ => return None
+        };
+
+        let mut j = 1;
+
+        while let [dot, Token::Identifier { value, .. }, ..] = &tokens[j..] {
+            if !dot.is_punctuation(Punctuation::Dot) {
+                break;
+            }
+
+            name.push('.');
+            name += value;
+
+            j += 2;
+        }
+
+        match tokens.get(j) {
+            Some(colon) if colon.is_punctuation(Punctuation::Colon) => Some((name, j)),
+            _ => None
+        }
+    }
+
+    /// Parse signal name if the tokens start with an event definition.
+    /// Returns signal name and the index of its arrow
+    /// 
+    /// `clicked =>` or `notify::label =>`
+    fn parse_signal_name(tokens: &[Token]) -> Option<(String, usize)> {
+        match tokens {
+            [Token::Identifier { value, .. }, arrow, ..] if arrow.is_punctuation(Punctuation::Arrow) => {
+                Some((value.clone(), 1))
+            }
+
+            [Token::Identifier { value, .. }, separator, Token::Identifier { value: detail, .. }, arrow, ..]
+                if separator.is_punctuation(Punctuation::DoubleColon) && arrow.is_punctuation(Punctuation::Arrow) => {
+                Some((format!("{}::{}", value, detail), 3))
+            }
+
+            _ => None
+        }
+    }
+
+    fn parse_menu_kind(token: &Token) -> Option<MenuKind> {
+        match token {
+            Token::Identifier { value, .. } => MenuKind::parse(value),
+            _ => None
+        }
+    }
+
+    /// Parse child type annotation from the square brackets tokens
+    /// 
+    /// `[titlebar]`, `[internal-child content_area]` or `[action response=ok default]`
+    fn parse_child_type(tokens: &[Token], begin: usize) -> Result<ChildType, ParseError> {
+        match tokens {
+            [keyword, ..] if keyword.is_identifier_value("action") => {
+                let mut response = None;
+                let mut default = false;
+                let mut j = 1;
+
+                while j < tokens.len() {
+                    match &tokens[j..] {
+                        [flag, ..] if flag.is_identifier_value("default") => {
+                            default = true;
+
+                            j += 1;
+                        }
+
+                        // response=ok or response = -5
+                        [keyword, equals, Token::Identifier { value, .. } | Token::Number { value, .. }, ..]
+                            if keyword.is_identifier_value("response") && equals.is_punctuation(Punctuation::Equals) => {
+                            response = Some(value.clone());
+
+                            j += 3;
+                        }
+
+                        [token, ..] => return Err(ParseError::IncorrectObjectDefinition {
+                            message: format!("Incorrect action widget annotation at offset {}", token.get_begin()),
+                            offset: token.get_begin()
+                        }),
+
+                        [] => unreachable!()
+                    }
+                }
+
+                match response {
+                    Some(response) => Ok(ChildType::Action { response, default }),
+
+                    None => Err(ParseError::IncorrectObjectDefinition {
+                        message: format!("Action widget must have a response, occured at offset {}", begin),
+                        offset: begin
+                    })
+                }
+            }
+
+            [Token::Identifier { value: keyword, .. }, Token::Identifier { value: name, .. }] if keyword == "internal-child" => {
+                Ok(ChildType::InternalChild(name.clone()))
+            }
+
+            [Token::Identifier { value, .. }] if value != "internal-child" => Ok(ChildType::Type(value.clone())),
+
+            _ => Err(ParseError::IncorrectObjectDefinition {
+                message: format!("Incorrect child type annotation at offset {}", begin),
+                offset: begin
+            })
+        }
+    }
+
+    /// Parse composite template starting from the `template` keyword.
+    /// Returns template and the index of its body token
+    /// 
+    /// `template $ClassName : Parent.Type {}`
+    fn parse_template(text: &[char], tokens: &[Token], context: &mut Context) -> Result<(Template, usize), ParseError> {
+        let incorrect_template = || ParseError::IncorrectObjectDefinition {
+            message: format!("Incorrect template definition at offset {}", tokens[0].get_begin()),
+            offset: tokens[0].get_begin()
+        };
+
+        let (class, length) = Self::parse_type_name(&tokens[1..]).ok_or_else(incorrect_template)?;
+
+        context.check_namespace(&class, tokens[1].get_begin())?;
+
+        let mut j = 1 + length;
+        let mut parent = None;
+
+        // : Parent.Type
+        if matches!(tokens.get(j), Some(token) if token.is_punctuation(Punctuation::Colon)) {
+            let (parent_class, length) = Self::parse_class_name(&tokens[j + 1..]).ok_or_else(incorrect_template)?;
+
+            context.check_namespace(&parent_class, tokens[j + 1].get_begin())?;
+
+            parent = Some(parent_class);
+            j += 1 + length;
+        }
+
+        match tokens.get(j) {
+            Some(Token::CurlyBrackets { .. }) => (),
+
+            Some(token) => return Err(ParseError::IncorrectObjectDefinition {
+                message: format!("Incorrect template definition at offset {}", token.get_begin()),
+                offset: token.get_begin()
+            }),
+
+            None => return Err(ParseError::IncorrectObjectDefinition {
+                message: format!("Template body expected at offset {}", tokens[0].get_begin()),
+                offset: tokens[0].get_begin()
+            })
+        }
+
+        // List item templates can omit the namespace
+        // 
+        // Gtk.BuilderListItemFactory { template ListItem {} }
+        let class = match (context.classes.last(), class.to_string().as_str(), &parent) {
+            (Some(factory), "ListItem", None) if factory == "Gtk.BuilderListItemFactory" => TypeName {
+                namespace: Some(String::from("Gtk")),
+                name: String::from("ListItem"),
+                external: false
+            },
+
+            _ => class
+        };
+
+        context.classes.push(parent.as_ref().unwrap_or(&class).to_string());
+
+        let children = Self::parse_block(text, &tokens[j], context);
+
+        context.classes.pop();
+
+        Ok((Template {
+            class,
+            parent,
+            children: children?
+        }, j))
+    }
+
+    /// Parse Gtk.BuilderListItemFactory template starting from the `template` keyword.
+    /// It's built as a separate interface, so it has its own objects scope
+    /// 
+    /// `template ListItem {}`
+    fn parse_list_item_template(text: &[char], tokens: &[Token], context: &mut Context) -> Result<(Template, usize), ParseError> {
+        let mut scope = Context {
+            classes: context.classes.clone(),
+            generated_ids: context.generated_ids,
+            namespaces: context.namespaces.clone(),
+            ..Context::default()
+        };
+
+        let (template, j) = Self::parse_template(text, tokens, &mut scope)?;
+
+        context.generated_ids = scope.generated_ids;
+
+        let mut root = template.into_interface();
+
+        let names = root.get_named_objects().into_iter().map(|(name, _)| name).collect::<Vec<String>>();
+
+        // Outer objects are only known once the whole blueprint is parsed,
+        // so the references to them are reported there
+        let (references, nested_references): (Vec<_>, Vec<_>) = scope.references.into_iter()
+            .partition(|(name, _)| names.contains(name));
+
+        Self::resolve_references(&mut root, &references)?;
+
+        context.nested_references.extend(nested_references);
+        context.nested_references.extend(scope.nested_references);
+
+        match root.children.pop() {
+            Some(Entry::Template(template)) => Ok((template, j)),
+            _ => unreachable!()
+        }
+    }
+
+    /// Parse menu or its part starting from the keyword.
+    /// Returns menu and the index of its body token
+    /// 
+    /// `menu id {}`, `section {}`, `submenu {}`, `item {}` or `item ("Label", "app.action", "icon")`
+    fn parse_menu(kind: MenuKind, text: &[char], tokens: &[Token], context: &mut Context) -> Result<(Menu, usize), ParseError> {
+        let mut id = None;
+        let mut j = 1;
+
+        if kind != MenuKind::Item {
+            if let Some(Token::Identifier { value, .. }) = tokens.get(1) {
+                id = Some(value.clone());
+                j = 2;
+            }
+        }
+
+        match tokens.get(j) {
+            Some(token @ Token::CurlyBrackets { .. }) => {
+                context.menu_depth += 1;
+
+                let children = Self::parse_block(text, token, context);
+
+                context.menu_depth -= 1;
+
+                let children = children?;
+
+                for child in &children {
+                    let correct = match child {
+                        Entry::Property(property) => property.value.get_literal().is_some() || matches!(property.value, PropertyValue::Translated(_)),
+                        Entry::Menu(menu) => kind != MenuKind::Item && menu.kind != MenuKind::Menu,
+                        _ => false
+                    };
+
+                    if !correct {
+                        return Err(ParseError::IncorrectMenuDefinition {
+                            message: format!("Incorrect {} content at offset {}", kind.as_str(), token.get_begin()),
+                            offset: token.get_begin()
+                        });
+                    }
+                }
+
+                Ok((Menu { kind, id, children }, j))
+            }
+
+            // item (_("Quit"), "app.quit", "icon")
+            Some(Token::Parentheses { tokens: args, begin, .. }) if kind == MenuKind::Item => {
+                let args = Self::split_list(args);
+
+                if args.is_empty() || args.len() > 3 {
+                    return Err(ParseError::IncorrectMenuDefinition {
+                        message: format!("Menu item must have from 1 to 3 arguments, occured at offset {}", begin),
+                        offset: *begin
+                    });
+                }
+
+                let mut children = Vec::new();
+
+                for (name, arg) in ["label", "action", "verb-icon"].iter().zip(args) {
+                    let value = match arg.as_slice() {
+                        [Token::Text { value, .. }] => PropertyValue::String(value.clone()),
+                        arg if Self::is_translated_string(arg) => PropertyValue::Translated(Self::parse_translated_string(arg)?),
+
+                        _ => return Err(ParseError::IncorrectMenuDefinition {
+                            message: format!("Menu item arguments must be strings, occured at offset {}", begin),
+                            offset: *begin
+                        })
+                    };
+
+                    children.push(Property::entry(name.to_string(), value));
+                }
+
+                Ok((Menu { kind, id, children }, j))
+            }
+
+            _ => Err(ParseError::IncorrectMenuDefinition {
+                message: format!("Incorrect {} definition at offset {}", kind.as_str(), tokens[0].get_begin()),
+                offset: tokens[0].get_begin()
+            })
+        }
+    }
+
+    /// Split list items separated by commas
+    /// 
+    /// `a, "b", _("c")` -> `[a]`, `["b"]`, `[_, ("c")]`
+    pub(super) fn split_list(tokens: &[Token]) -> Vec<Vec<Token>> {
+        let mut items = vec![Vec::new()];
+
+        for token in tokens {
+            if token.is_punctuation(Punctuation::Comma) {
+                items.push(Vec::new());
+            }
+
+            else {
+                items.last_mut().unwrap().push(token.clone());
+            }
+        }
+
+        // Allow trailing comma: `[a, b,]`
+        if items.last().map(|item| item.is_empty()) == Some(true) {
+            items.pop();
+        }
+
+        items
+    }
+
+    /// Parse property value from its tokens between the colon and the ending semicolon
+    /// 
+    /// Translator comment is the last block comment between
+    /// the property colon and its value
+    /// 
+    /// `label: /* Translators: comment */ _("Hello");`
+    fn parse_property_value(text: &[char], colon: &Token, tokens: &[Token], context: &mut Context) -> Result<PropertyValue, ParseError> {
+        let gap = text[colon.get_end() + 1..tokens[0].get_begin()].iter().collect::<String>();
+
+        let comments = Tokenizer::parse_with_trivia(gap)?.into_iter().rev().find_map(|token| {
+            match token {
+                Token::Comment { value, .. } if value.starts_with("/*") => Some(value[2..value.len() - 2].trim().to_string()),
+                _ => None
+            }
+        });
+
+        // [Identifier(_)] [Parentheses(..)]  Example: _("Hi")
+        if Self::is_translated_string(tokens) {
+            let mut string = Self::parse_translated_string(tokens)?;
+
+            string.comments = comments;
+
+            Ok(PropertyValue::Translated(string))
+        }
+
+        // [Identifier(bind)] [Identifier(source)] [Punctuation(.)] [Identifier(property)] [Identifier(flags)]...  Example: bind switch.active inverted
+        else if tokens[0].is_identifier_value("bind") {
+            Ok(PropertyValue::Binding(Self::parse_binding(tokens, context)?))
+        }
+
+        // [Identifier(expr)] ...  Example: expr item as <Gtk.StringObject>.string
+        else if tokens[0].is_identifier_value("expr") && tokens.len() > 1 {
+            Ok(PropertyValue::Expression(ExpressionParser::parse_tokens(&tokens[1..], context)?))
+        }
+
+        // [Text(..)]  Example: "Hi"
+        else if let [Token::Text { value, .. }] = tokens {
+            Ok(PropertyValue::String(value.clone()))
+        }
+
+        // [SquareBrackets(..)]  Example: ["A", _("B")]
+        else if let [Token::SquareBrackets { tokens: items, begin, .. }] = tokens {
+            let mut array = Vec::new();
+
+            // GtkBuilder can only translate the whole strv property,
+            // so the items must be translated the same way as the first one
+            let mut first_translation = None;
+
+            for item in Self::split_list(items) {
+                let begin = item.first().map(|token| token.get_begin()).unwrap_or(*begin);
+
+                let value = match item.as_slice() {
+                    [Token::Text { value, .. }] => PropertyValue::String(value.clone()),
+                    item if Self::is_translated_string(item) => PropertyValue::Translated(Self::parse_translated_string(item)?),
+
+                    _ => return Err(ParseError::IncorrectPropertyDefinition {
+                        message: format!("Array items must be strings, occured at offset {}", begin),
+                        offset: begin
+                    })
+                };
+
+                // Translation context of the translatable item
+                let translation = match &value {
+                    PropertyValue::Translated(string) => Some(string.context.clone()),
+                    _ => None
+                };
+
+                match &first_translation {
+                    None => first_translation = Some(translation),
+
+                    Some(first_translation) if *first_translation != translation => {
+                        return Err(ParseError::IncorrectPropertyDefinition {
+                            message: format!("Array items must be all translatable with the same context or all not, occured at offset {}", begin),
+                            offset: begin
+                        });
+                    }
+
+                    _ => ()
+                }
+
+                array.push(value);
+            }
+
+            Ok(PropertyValue::Array(array))
+        }
+
+        // [Number(..)]  Example: 12, -1.5 or 0x1F
+        else if let [Token::Number { value, begin, .. }] = tokens {
+            Self::parse_number(value, *begin)
+        }
+
+        // [Identifier(..)] [Punctuation(|)]...  Example: start or top | bottom
+        else if tokens.iter().all(|token| matches!(token, Token::Identifier { .. } | Token::Number { .. } | Token::Punctuation { .. })) {
+            match Self::parse_literal(tokens) {
+                // typeof<Adw.ActionRow>
+                Some(PropertyValue::TypeName(type_name)) => {
+                    context.check_namespace(&type_name, tokens[2].get_begin())?;
+
+                    Ok(PropertyValue::TypeName(type_name))
+                }
+
+                Some(value) => Ok(value),
+                None => Err(ParseError::IncorrectPropertyDefinition {
+                    message: format!("Incorrect property value at offset {}", tokens[0].get_begin()),
+                    offset: tokens[0].get_begin()
+                })
+            }
+        }
+
+        else {
+            let begin = tokens[0].get_begin();
+
+            let children = Self::parse_entries(text, tokens, context)?.root.children;
+
+            if children.len() != 1 {
+                return Err(ParseError::IncorrectPropertyDefinition {
+                    message: format!("Property value must be a single object, occured at offset {}", begin),
+                    offset: begin
+                });
+            }
+
+            match &children[0] {
+                Entry::Object(obj) => Ok(PropertyValue::Entry(obj.clone())),
+
+                Entry::Menu(menu) => {
+                    let mut menu = menu.clone();
+
+                    if menu.id.is_none() {
+                        menu.id = Some(context.generate_id("menu"));
+                    }
+
+                    Ok(PropertyValue::Menu(menu))
+                }
+
+                _ => Err(ParseError::IncorrectPropertyDefinition {
+                    message: format!("Property value must be an object, occured at offset {}", begin),
+                    offset: begin
+                })
+            }
+        }
+    }
+
+    /// Parse literal property value
+    /// 
+    /// `true`, `null`, `start`, `top | bottom` or `typeof<Gtk.Box>`
+    fn parse_literal(tokens: &[Token]) -> Option<PropertyValue> {
+        match tokens {
+            [Token::Identifier { value, .. }] => Some(match value.as_str() {
+                "true" => PropertyValue::Bool(true),
+                "false" => PropertyValue::Bool(false),
+                "null" => PropertyValue::Null,
+
+                _ => PropertyValue::Ident(value.clone())
+            }),
+
+            // typeof<Gtk.Box>
+            [keyword, less, type_name @ .., greater] if keyword.is_identifier_value("typeof") => {
+                if !less.is_punctuation(Punctuation::LessThan) || !greater.is_punctuation(Punctuation::GreaterThan) {
+                    return None;
+                }
+
+                match Self::parse_type_name(type_name) {
+                    Some((type_name, length)) if length == tokens.len() - 3 => Some(PropertyValue::TypeName(type_name)),
+                    _ => None
+                }
+            }
+
+            // top | bottom
+            [Token::Identifier { .. }, ..] => {
+                let mut flags = Vec::new();
+
+                for (k, token) in tokens.iter().enumerate() {
+                    match token {
+                        Token::Identifier { value, .. } if k % 2 == 0 => flags.push(value.clone()),
+                        token if k % 2 == 1 && token.is_punctuation(Punctuation::Pipe) => (),
+
+                        _ => return None
+                    }
+                }
+
+                (tokens.len() % 2 == 1).then_some(PropertyValue::Flags(flags))
+            }
+
+            _ => None
+        }
+    }
+
+    /// Parse number token value starting at `offset`
+    /// 
+    /// `12`, `-1.5`, `1_000`, `0x1F`, `0o17` or `0b101`
+    pub(super) fn parse_number(value: &str, offset: usize) -> Result<PropertyValue, ParseError> {
+        let value = value.replace('_', "");
+
+        let (negative, digits) = match value.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, value.as_str())
+        };
+
+        let (radix, digits) = match digits.get(..2) {
+            Some("0x") => (16, &digits[2..]),
+            Some("0o") => (8, &digits[2..]),
+            Some("0b") => (2, &digits[2..]),
+
+            _ if digits.contains('.') => return match value.parse::<f64>() {
+                Ok(value) => Ok(PropertyValue::Float(value)),
+                Err(_) => Err(ParseError::IncorrectPropertyDefinition {
+                    message: format!("Incorrect number at offset {}", offset),
+                    offset
+                })
+            },
+
+            _ => (10, digits)
+        };
+
+        // Sign is parsed with the digits, so the minimal integer fits as well
+        let digits = if negative { format!("-{}", digits) } else { digits.to_string() };
+
+        match i64::from_str_radix(&digits, radix) {
+            Ok(number) => Ok(PropertyValue::Int(number)),
+
+            // GtkBuilder properties can't hold larger integers
+            Err(err) if matches!(err.kind(), IntErrorKind::PosOverflow | IntErrorKind::NegOverflow) => {
+                Err(ParseError::IncorrectPropertyDefinition {
+                    message: format!("Integer is out of the 64 bit range at offset {}", offset),
+                    offset
+                })
+            }
+
+            Err(_) => Err(ParseError::IncorrectPropertyDefinition {
+                message: format!("Incorrect number at offset {}", offset),
+                offset
+            })
+        }
+    }
+
+    /// Parse property binding from its tokens, starting from the `bind` keyword
+    /// 
+    /// `bind switch.active inverted` or `bind template.item as <Gtk.StringObject>.string`
+    fn parse_binding(tokens: &[Token], context: &mut Context) -> Result<Binding, ParseError> {
+        if tokens.len() == 1 {
+            return Err(ParseError::IncorrectPropertyDefinition {
+                message: format!("Binding source is not specified at offset {}", tokens[0].get_begin()),
+                offset: tokens[0].get_begin()
+            });
+        }
+
+        let (expression, length) = ExpressionParser::parse_binding(&tokens[1..], context)?;
+
+        let mut binding = Binding {
+            expression,
+            flags: Vec::new()
+        };
+
+        if !matches!(binding.expression, Expression::Lookup { .. }) {
+            return Err(ParseError::IncorrectPropertyDefinition {
+                message: format!("Binding source must be a property lookup, occured at offset {}", tokens[1].get_begin()),
+                offset: tokens[1].get_begin()
+            });
+        }
+
+        for token in &tokens[1 + length..] {
+            let flag = match token {
+                Token::Identifier { value, .. } => BindingFlag::parse(value),
+                _ => None
+            };
+
+            match flag {
+                // Flags can only be set for a single lookup, `<binding>` element doesn't support them
+                Some(_) if binding.get_source().is_none() => return Err(ParseError::IncorrectPropertyDefinition {
+                    message: format!("Binding flags require the object.property source, occured at offset {}", token.get_begin()),
+                    offset: token.get_begin()
+                }),
+
+                Some(flag) => binding.flags.push(flag),
+
+                None => return Err(ParseError::IncorrectPropertyDefinition {
+                    message: format!("Unknown binding flag \"{}\" at offset {}", token.get_value().unwrap_or_default(), token.get_begin()),
+                    offset: token.get_begin()
+                })
+            }
+        }
+
+        Ok(binding)
+    }
+
+    /// Parse signal handler from its tokens between the `=>` and the ending semicolon
+    /// 
+    /// `$on_clicked(object) swapped after`
+    fn parse_signal(name: String, tokens: &[Token], context: &mut Context) -> Result<Entry, ParseError> {
+        let handler = match tokens.get(1) {
+            Some(Token::Identifier { value, .. }) if !value.contains('-') => value.clone(),
+
+            _ => return Err(ParseError::IncorrectEventDefinition {
+                message: format!("Incorrect signal handler name at offset {}", tokens[0].get_begin()),
+                offset: tokens[0].get_begin()
+            })
+        };
+
+        let mut object = None;
+        let mut flags = &tokens[2..];
+
+        // $on_clicked(object)
+        if let Some(Token::Parentheses { tokens: args, begin, .. }) = flags.first() {
+            match args.as_slice() {
+                [] => (),
+
+                [Token::Identifier { value, begin, .. }] => {
+                    context.references.push((value.clone(), *begin));
+
+                    object = Some(value.clone());
+                }
+
+                _ => return Err(ParseError::IncorrectEventDefinition {
+                    message: format!("Signal handler can only take a single object name, occured at offset {}", begin),
+                    offset: *begin
+                })
+            }
+
+            flags = &flags[1..];
+        }
+
+        let mut entry = Signal::entry(name, handler, object);
+
+        let Entry::Signal(signal) = &mut entry else {
+            unreachable!()
+        };
+
+        for flag in flags {
+            match flag.get_value().filter(|_| flag.is_identifier()).as_deref() {
+                Some("swapped") => signal.swapped = Some(true),
+                Some("not-swapped") => signal.swapped = Some(false),
+                Some("after") => signal.after = true,
+
+                _ => return Err(ParseError::IncorrectEventDefinition {
+                    message: format!("Unknown signal flag at offset {}", flag.get_begin()),
+                    offset: flag.get_begin()
+                })
+            }
+        }
+
+        Ok(entry)
+    }
+
+    /// Check if tokens are a translatable string call
+    /// 
+    /// `_("Hello")` or `C_("context", "Hello")`
+    pub(super) fn is_translated_string(tokens: &[Token]) -> bool {
+        tokens.len() == 2 &&
+        (tokens[0].is_identifier_value("_") || tokens[0].is_identifier_value("C_")) &&
+        matches!(tokens[1], Token::Parentheses { .. })
+    }
+
+    /// Parse translatable string from `_("Hello")` or `C_("context", "Hello")` tokens
+    pub(super) fn parse_translated_string(tokens: &[Token]) -> Result<TranslatedString, ParseError> {
+        let offset = tokens[0].get_begin();
+
+        let args = match &tokens[1] {
+            Token::Parentheses { tokens, .. } => tokens,
+            _ => unreachable!()
+        };
+
+        match args.as_slice() {
+            // _("Hello")
+            [Token::Text { value, .. }] if tokens[0].is_identifier_value("_") => Ok(TranslatedString::new(value)),
+
+            // C_("context", "Hello")
+            [Token::Text { value: context, .. }, comma, Token::Text { value, .. }] if tokens[0].is_identifier_value("C_") && comma.is_punctuation(Punctuation::Comma) => {
+                Ok(TranslatedString {
+                    text: value.clone(),
+                    context: Some(context.clone()),
+                    comments: None
+                })
+            }
+
+            _ => Err(ParseError::IncorrectSyntax {
+                message: format!("Incorrect translatable string at offset {}", offset),
+                offset
+            })
+        }
+    }
 }
@@ -1,10 +1,89 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Punctuation {
+    /// `:`
+    Colon,
+
+    /// `::`
+    DoubleColon,
+
+    /// `;`
+    Semicolon,
+
+    /// `,`
+    Comma,
+
+    /// `.`
+    Dot,
+
+    /// `|`
+    Pipe,
+
+    /// `=>`
+    Arrow,
+
+    /// `=`
+    Equals,
+
+    /// `<`
+    LessThan,
+
+    /// `>`
+    GreaterThan,
+
+    /// `$`
+    Dollar
+}
+
+impl Punctuation {
+    /// Parse punctuation from the beginning of the text
+    pub fn parse(text: &[char]) -> Option<Self> {
+        match text {
+            [':', ':', ..] => Some(Self::DoubleColon),
+            ['=', '>', ..] => Some(Self::Arrow),
+
+            [':', ..] => Some(Self::Colon),
+            [';', ..] => Some(Self::Semicolon),
+            [',', ..] => Some(Self::Comma),
+            ['.', ..] => Some(Self::Dot),
+            ['|', ..] => Some(Self::Pipe),
+            ['=', ..] => Some(Self::Equals),
+            ['<', ..] => Some(Self::LessThan),
+            ['>', ..] => Some(Self::GreaterThan),
+            ['$', ..] => Some(Self::Dollar),
+
+            _ => None
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Colon       => ":",
+            Self::DoubleColon => "::",
+            Self::Semicolon   => ";",
+            Self::Comma       => ",",
+            Self::Dot         => ".",
+            Self::Pipe        => "|",
+            Self::Arrow       => "=>",
+            Self::Equals      => "=",
+            Self::LessThan    => "<",
+            Self::GreaterThan => ">",
+            Self::Dollar      => "$"
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Token {
     /// `"Example string"`
     Text {
         begin: usize,
         end: usize,
-        value: String
+
+        /// Decoded string value
+        value: String,
+
+        /// Source text of the string, including quotes and escape sequences
+        raw: String
     },
     
     /// `(Example "parentheses")`
@@ -14,8 +93,6 @@ pub enum Token {
         tokens: Vec<Token>
     },
 
-    // TODO:
-    
     /// `[Example, "square brackets"]`
     SquareBrackets {
         begin: usize,
@@ -30,8 +107,29 @@ pub enum Token {
         tokens: Vec<Token>
     },
 
-    /// `example values`
-    Other {
+    /// `default-width`, `Gtk`
+    Identifier {
+        begin: usize,
+        end: usize,
+        value: String
+    },
+
+    /// `12`, `-1.5`, `0x1F`
+    Number {
+        begin: usize,
+        end: usize,
+        value: String
+    },
+
+    /// `:`, `;`, `=>`
+    Punctuation {
+        begin: usize,
+        end: usize,
+        value: Punctuation
+    },
+
+    /// `// comment` or `/* comment */`, only kept by `Tokenizer::parse_with_trivia`
+    Comment {
         begin: usize,
         end: usize,
         value: String
@@ -39,24 +137,27 @@ pub enum Token {
 }
 
 impl Token {
-    pub fn is_other(&self) -> bool {
-        match self {
-            Self::Other { .. } => true,
-            _ => false
-        }
+    pub fn is_identifier(&self) -> bool {
+        matches!(self, Self::Identifier { .. })
     }
 
-    pub fn is_other_value<T: ToString>(&self, other: T) -> bool {
+    pub fn is_identifier_value<T: ToString>(&self, identifier: T) -> bool {
         match self {
-            Self::Other { value, .. } => *value == other.to_string(),
+            Self::Identifier { value, .. } => *value == identifier.to_string(),
             _ => false
         }
     }
 
+    pub fn is_punctuation(&self, punctuation: Punctuation) -> bool {
+        matches!(self, Self::Punctuation { value, .. } if *value == punctuation)
+    }
+
     pub fn get_value(&self) -> Option<String> {
         match self {
-            Token::Text  { value, .. } |
-            Token::Other { value, .. } => Some(value.clone()),
+            Token::Text        { value, .. } |
+            Token::Identifier  { value, .. } |
+            Token::Number      { value, .. } => Some(value.clone()),
+            Token::Punctuation { value, .. } => Some(value.as_str().to_string()),
             _ => None
         }
     }
@@ -67,7 +168,10 @@ impl Token {
             Token::Parentheses    { begin, .. } => begin,
             Token::SquareBrackets { begin, .. } => begin,
             Token::CurlyBrackets  { begin, .. } => begin,
-            Token::Other          { begin, .. } => begin
+            Token::Identifier     { begin, .. } => begin,
+            Token::Number         { begin, .. } => begin,
+            Token::Punctuation    { begin, .. } => begin,
+            Token::Comment        { begin, .. } => begin
         }
     }
 
@@ -77,7 +181,10 @@ impl Token {
             Token::Parentheses    { end, .. } => end,
             Token::SquareBrackets { end, .. } => end,
             Token::CurlyBrackets  { end, .. } => end,
-            Token::Other          { end, .. } => end
+            Token::Identifier     { end, .. } => end,
+            Token::Number         { end, .. } => end,
+            Token::Punctuation    { end, .. } => end,
+            Token::Comment        { end, .. } => end
         }
     }
 }
@@ -1,4 +1,4 @@
-use std::io::{Error, ErrorKind};
+use std::io::Error;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TokenizeError {
@@ -18,6 +18,18 @@ pub enum TokenizeError {
         begin: usize,
         end: usize,
         wrong_string: String
+    },
+    IncorrectComment {
+        message: String,
+        begin: usize,
+        end: usize,
+        wrong_string: String
+    },
+    IncorrectNumber {
+        message: String,
+        begin: usize,
+        end: usize,
+        wrong_string: String
     }
 }
 
@@ -26,7 +38,9 @@ impl TokenizeError {
         match self {
             Self::IncorrectChar     { message, .. } => message,
             Self::IncorrectString   { message, .. } => message,
-            Self::IncorrectBrackets { message, .. } => message
+            Self::IncorrectBrackets { message, .. } => message,
+            Self::IncorrectComment  { message, .. } => message,
+            Self::IncorrectNumber   { message, .. } => message
         }.as_str()
     }
 
@@ -35,7 +49,9 @@ impl TokenizeError {
             TokenizeError::IncorrectChar { offset, .. } => *offset += num,
 
             TokenizeError::IncorrectString { begin, end, .. } |
-            TokenizeError::IncorrectBrackets { begin, end, .. } => {
+            TokenizeError::IncorrectBrackets { begin, end, .. } |
+            TokenizeError::IncorrectComment { begin, end, .. } |
+            TokenizeError::IncorrectNumber { begin, end, .. } => {
                 *begin += num;
                 *end += num;
             }
@@ -45,8 +61,8 @@ impl TokenizeError {
     }
 }
 
-impl Into<Error> for TokenizeError {
-    fn into(self) -> Error {
-        Error::new(ErrorKind::Other, self.get_message())
+impl From<TokenizeError> for Error {
+    fn from(err: TokenizeError) -> Self {
+        Error::other(err.get_message())
     }
 }
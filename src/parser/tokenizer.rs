@@ -1,6 +1,6 @@
 use std::collections::VecDeque;
 
-use super::token::Token;
+use super::token::{Token, Punctuation};
 use super::tokenize_error::TokenizeError;
 
 pub struct Tokenizer;
@@ -17,7 +17,7 @@ impl Tokenizer {
     ///     let mut list = Vec::new();
     /// 
     ///     for token in tokens {
-    ///         if let Token::Other { value, .. } = token {
+    ///         if let Token::Identifier { value, .. } = token {
     ///             list.push(value);
     ///         }
     ///     }
@@ -26,103 +26,123 @@ impl Tokenizer {
     /// }
     /// ```
     pub fn parse<T: ToString>(text: T) -> Result<Vec<Token>, TokenizeError> {
-        let mut tokens = Vec::new();
-        let mut word = String::new();
+        let text = text.to_string().chars().collect::<Vec<char>>();
+
+        Self::tokenize(&text, 0, text.len(), false)
+    }
 
+    /// Parse syntax tokens from input string, keeping comments as `Token::Comment`
+    /// 
+    /// ```
+    /// use gtk_ui_builder::parser::prelude::*;
+    /// 
+    /// let tokens = Tokenizer::parse_with_trivia("a // comment").expect("Failed to tokenize");
+    /// 
+    /// assert_eq!(tokens[1], Token::Comment { begin: 2, end: 11, value: String::from("// comment") });
+    /// ```
+    pub fn parse_with_trivia<T: ToString>(text: T) -> Result<Vec<Token>, TokenizeError> {
         let text = text.to_string().chars().collect::<Vec<char>>();
 
-        let mut token_begin = 0;
-        let mut i = 0;
+        Self::tokenize(&text, 0, text.len(), true)
+    }
 
-        while i < text.len() {
-            // Other token end
-            if text[i].is_whitespace() {
-                // Two ifs to avoid else execution
-                if !word.is_empty() {
-                    tokens.push(Token::Other {
-                        begin: token_begin,
-                        end: i - 1,
-                        value: word
-                    });
+    /// Tokenize text between the `begin` and `end` char indexes.
+    /// Tokens offsets are counted from the beginning of the whole text
+    fn tokenize(text: &[char], begin: usize, end: usize, trivia: bool) -> Result<Vec<Token>, TokenizeError> {
+        let mut tokens = Vec::new();
 
-                    word = String::new();
-                }
+        let mut i = begin;
 
-                token_begin = i + 1;
+        while i < end {
+            let token_begin = i;
+
+            // Skip whitespaces
+            if text[i].is_whitespace() {
+                i += 1;
+
+                continue;
             }
 
-            // Parse string
-            else if text[i] == '"' {
-                // Save not empty word as Other token
-                if !word.is_empty() {
-                    tokens.push(Token::Other {
+            // Parse comment
+            // 
+            // `// line comment` or `/* block comment */`
+            else if text[i] == '/' && matches!(text.get(i + 1), Some('/') | Some('*')) {
+                i = match Self::find_comment_end(text, i) {
+                    Some(comment_end) => comment_end,
+
+                    // Return Err if block comment end wasn't found (/* comment)
+                    None => return Err(TokenizeError::IncorrectComment {
+                        message: format!("Unterminated block comment found from offset {} to {}", token_begin, end - 1),
                         begin: token_begin,
-                        end: i - 1,
-                        value: word
-                    });
+                        end: end - 1,
+                        wrong_string: text[token_begin..end].iter().collect()
+                    })
+                };
 
-                    token_begin = i;
-                    word = String::new();
+                if trivia {
+                    tokens.push(Token::Comment {
+                        begin: token_begin,
+                        end: i,
+                        value: text[token_begin..=i].iter().collect()
+                    });
                 }
+            }
 
-                let mut correct_str = false;
-                i += 1;
+            // Parse string
+            // 
+            // `"string"`, `'string'` or `"adjacent " "strings"`
+            else if text[i] == '"' || text[i] == '\'' {
+                let (mut value, mut string_end) = Self::parse_string(text, i)?;
 
-                while i < text.len() {
-                    // TODO: slashes encoding
-                    if text[i] == '"' {
-                        correct_str = true;
+                // Concatenate adjacent strings
+                loop {
+                    let next = (string_end + 1..end).find(|j| !text[*j].is_whitespace());
 
-                        break;
-                    }
+                    match next {
+                        Some(next) if text[next] == '"' || text[next] == '\'' => {
+                            let (next_value, next_end) = Self::parse_string(text, next)?;
 
-                    else {
-                        word.push(text[i]);
-                    }
+                            value += &next_value;
+                            string_end = next_end;
+                        }
 
-                    i += 1;
+                        _ => break
+                    }
                 }
 
-                // Return Err if string end wasn't found ("string)
-                if !correct_str {
-                    return Err(TokenizeError::IncorrectString {
-                        message: format!("Incorrect string format found from offset {} to {}", token_begin, i),
-                        begin: token_begin,
-                        end: i,
-                        wrong_string: word
-                    });
-                }
+                i = string_end;
 
                 tokens.push(Token::Text {
                     begin: token_begin,
                     end: i,
-                    value: word
+                    value,
+                    raw: text[token_begin..=i].iter().collect()
                 });
-
-                word = String::new();
-                token_begin = i + 1;
             }
 
             // Parse brackets
             else if text[i] == '(' || text[i] == '[' || text[i] == '{' {
-                // Save not empty word as Other token
-                if !word.is_empty() {
-                    tokens.push(Token::Other {
-                        begin: token_begin,
-                        end: i - 1,
-                        value: word
-                    });
-
-                    token_begin = i;
-                    word = String::new();
-                }
-
                 let mut brackets_stack = VecDeque::from([text[i]]);
                 let mut correct_order = false;
 
                 i += 1;
 
-                while i < text.len() {
+                while i < end {
+                    // Brackets inside of strings and comments are not counted.
+                    // Strings are decoded later, if the brackets are tokenized at all
+                    let skipped_end = match text[i] {
+                        '"' | '\'' => Some(Self::find_string_end(text, i)?),
+                        '/' if matches!(text.get(i + 1), Some('/') | Some('*')) => Self::find_comment_end(text, i),
+
+                        _ => None
+                    };
+
+                    if let Some(skipped_end) = skipped_end {
+                        i = skipped_end + 1;
+
+                        continue;
+                    }
+
                     if let Some(rev_bracket) = Self::get_rev_bracket(text[i]) {
                         if brackets_stack.back() == Some(&rev_bracket) {
                             brackets_stack.pop_back();
@@ -139,7 +159,6 @@ impl Tokenizer {
                         }
                     }
 
-                    word.push(text[i]);
                     i += 1;
                 }
 
@@ -149,11 +168,23 @@ impl Tokenizer {
                         message: format!("Incorrect brackets format found from offset {} to {}", token_begin, i),
                         begin: token_begin,
                         end: i,
-                        wrong_string: word
+                        wrong_string: text[token_begin + 1..i.min(end)].iter().collect()
                     });
                 }
 
-                let sub_tokens = Self::inc_tokens_offsets(Self::parse(word)?, token_begin + 1);
+                // Event body is a script which doesn't follow the blueprint grammar,
+                // so it's kept untokenized
+                // 
+                // clicked => { print("Hello!"); }
+                let lazy = text[token_begin] == '{' && matches!(tokens.last(), Some(token) if token.is_punctuation(Punctuation::Arrow));
+
+                let sub_tokens = if lazy {
+                    Vec::new()
+                }
+
+                else {
+                    Self::tokenize(text, token_begin + 1, i, trivia)?
+                };
 
                 tokens.push(match &text[token_begin] {
                     '(' => Token::Parentheses    { begin: token_begin, end: i, tokens: sub_tokens },
@@ -161,40 +192,188 @@ impl Tokenizer {
                     '{' => Token::CurlyBrackets  { begin: token_begin, end: i, tokens: sub_tokens },
                     _ => unreachable!()
                 });
+            }
+
+            // Parse identifier
+            // 
+            // `default-width`, `Gtk`, `_`
+            else if text[i].is_alphabetic() || text[i] == '_' {
+                while i + 1 < end && Self::is_identifier_char(text[i + 1]) {
+                    i += 1;
+                }
+
+                tokens.push(Token::Identifier {
+                    begin: token_begin,
+                    end: i,
+                    value: text[token_begin..=i].iter().collect()
+                });
+            }
 
-                word = String::new();
-                token_begin = i + 1;
+            // Parse number
+            // 
+            // `12`, `-1.5`, `1_000`, `0x1F`
+            else if text[i].is_ascii_digit() || (text[i] == '-' && matches!(text.get(i + 1), Some(char) if char.is_ascii_digit())) {
+                while i + 1 < end && (Self::is_identifier_char(text[i + 1]) || Self::is_decimal_point(text, i + 1)) {
+                    i += 1;
+                }
+
+                let value = text[token_begin..=i].iter().collect::<String>();
+
+                // Return Err if number is malformed (4.0.1, 1-2, 0xZ)
+                if !Self::is_number(&value) {
+                    return Err(TokenizeError::IncorrectNumber {
+                        message: format!("Incorrect number found from offset {} to {}", token_begin, i),
+                        begin: token_begin,
+                        end: i,
+                        wrong_string: value
+                    });
+                }
+
+                tokens.push(Token::Number {
+                    begin: token_begin,
+                    end: i,
+                    value
+                });
             }
 
-            // Push Other token character
-            // There may be a situation like {}; where ; will be parsed as Other
-            // while {} as CurlyBrackets, so technically ; will be the first character. This is wrong
-            // so we need check previous character
-            else { /* if Self::is_normal_char(text[i], word.is_empty() && (if i > 0 { text[i - 1].is_whitespace() } else { true })) {*/
-                word.push(text[i]);
+            // Parse punctuation
+            // 
+            // `:`, `::`, `=>`, `;`
+            else if let Some(punctuation) = Punctuation::parse(&text[i..end]) {
+                i += punctuation.as_str().len() - 1;
+
+                tokens.push(Token::Punctuation {
+                    begin: token_begin,
+                    end: i,
+                    value: punctuation
+                });
             }
 
-            // Wrong Other token character
-            /*else {
+            // Wrong character
+            else {
                 return Err(TokenizeError::IncorrectChar {
                     message: format!("Incorrect character ({}) found at offset {}", text[i], i),
-                    wrong_string: word,
+                    wrong_string: text[i].to_string(),
                     offset: i
                 });
-            }*/
+            }
 
             i += 1;
         }
 
-        if !word.is_empty() {
-            tokens.push(Token::Other {
-                begin: token_begin,
-                end: text.len() - 1,
-                value: word
+        Ok(tokens)
+    }
+
+    /// Parse string starting from its quote at `begin`.
+    /// Returns decoded string value and the index of its closing quote
+    fn parse_string(text: &[char], begin: usize) -> Result<(String, usize), TokenizeError> {
+        let quote = text[begin];
+
+        let mut value = String::new();
+        let mut i = begin + 1;
+
+        while i < text.len() && text[i] != quote {
+            if text[i] != '\\' {
+                value.push(text[i]);
+                i += 1;
+
+                continue;
+            }
+
+            let (char, length) = match text.get(i + 1) {
+                Some('"')  => (Some('"'), 2),
+                Some('\'') => (Some('\''), 2),
+                Some('\\') => (Some('\\'), 2),
+                Some('n')  => (Some('\n'), 2),
+                Some('t')  => (Some('\t'), 2),
+                Some('r')  => (Some('\r'), 2),
+
+                // \u00A0
+                Some('u') => {
+                    let digits = text[i + 2..].iter()
+                        .take(4)
+                        .take_while(|char| char.is_ascii_hexdigit())
+                        .count();
+
+                    let char = match digits {
+                        4 => char::from_u32(u32::from_str_radix(&text[i + 2..i + 6].iter().collect::<String>(), 16).unwrap()),
+                        _ => None
+                    };
+
+                    (char, 2 + digits)
+                }
+
+                _ => (None, 2)
+            };
+
+            match char {
+                Some(char) => value.push(char),
+
+                None => {
+                    let end = (i + length).min(text.len()) - 1;
+
+                    return Err(TokenizeError::IncorrectString {
+                        message: format!("Invalid escape sequence found from offset {} to {}", i, end),
+                        begin: i,
+                        end,
+                        wrong_string: text[i..=end].iter().collect()
+                    });
+                }
+            }
+
+            i += length;
+        }
+
+        // Return Err if string end wasn't found ("string)
+        if i >= text.len() {
+            return Err(TokenizeError::IncorrectString {
+                message: format!("Incorrect string format found from offset {} to {}", begin, i),
+                begin,
+                end: i,
+                wrong_string: value
             });
         }
 
-        Ok(tokens)
+        Ok((value, i))
+    }
+
+    /// Find index of the closing quote of the string starting at `begin`
+    /// without decoding its escape sequences
+    fn find_string_end(text: &[char], begin: usize) -> Result<usize, TokenizeError> {
+        let mut i = begin + 1;
+
+        while i < text.len() && text[i] != text[begin] {
+            i += if text[i] == '\\' { 2 } else { 1 };
+        }
+
+        // Return Err if string end wasn't found ("string)
+        if i >= text.len() {
+            return Err(TokenizeError::IncorrectString {
+                message: format!("Incorrect string format found from offset {} to {}", begin, text.len()),
+                begin,
+                end: text.len(),
+                wrong_string: text[begin + 1..].iter().collect()
+            });
+        }
+
+        Ok(i)
+    }
+
+    /// Find index of the last character of the comment starting at `begin`.
+    /// Returns `None` if the block comment is not terminated
+    fn find_comment_end(text: &[char], begin: usize) -> Option<usize> {
+        // /* block comment */
+        if text.get(begin + 1) == Some(&'*') {
+            (begin + 3..text.len()).find(|i| text[*i - 1] == '*' && text[*i] == '/')
+        }
+
+        // // line comment
+        else {
+            match text[begin..].iter().position(|char| *char == '\n') {
+                Some(length) => Some(begin + length - 1),
+                None => Some(text.len() - 1)
+            }
+        }
     }
 
     fn get_rev_bracket(bracket: char) -> Option<char> {
@@ -211,40 +390,34 @@ impl Tokenizer {
         }
     }
 
-    /*fn is_normal_char(char: char, first: bool) -> bool {
-        !first ||
-        (char >= 'a' && char <= 'z') ||
-        (char >= 'A' && char <= 'Z') ||
-        (char >= '0' && char <= '9') ||
-        char == '.' || char == '_' || char == '='
-    }*/
-
-    fn inc_tokens_offsets(mut tokens: Vec<Token>, offset: usize) -> Vec<Token> {
-        for token in &mut tokens {
-            match token {
-                Token::Text  { begin, end, .. } => { *begin += offset; *end += offset; },
-                Token::Other { begin, end, .. } => { *begin += offset; *end += offset; },
-
-                Token::Parentheses { begin, end, tokens } => {
-                    *begin += offset;
-                    *end += offset;
-                    *tokens = Self::inc_tokens_offsets(tokens.clone(), offset);
-                },
-
-                Token::SquareBrackets { begin, end, tokens } => {
-                    *begin += offset;
-                    *end += offset;
-                    *tokens = Self::inc_tokens_offsets(tokens.clone(), offset);
-                },
-
-                Token::CurlyBrackets  { begin, end, tokens } => {
-                    *begin += offset;
-                    *end += offset;
-                    *tokens = Self::inc_tokens_offsets(tokens.clone(), offset);
-                }
+    fn is_identifier_char(char: char) -> bool {
+        char.is_alphanumeric() || char == '_' || char == '-'
+    }
+
+    /// Check if the dot at `i` is followed by a digit, `1.5`
+    fn is_decimal_point(text: &[char], i: usize) -> bool {
+        text[i] == '.' && matches!(text.get(i + 1), Some(char) if char.is_ascii_digit())
+    }
+
+    /// Check if the string is a decimal, `12`, `-1.5`, `1_000`,
+    /// or a prefixed integer number, `0x1F`, `0o17`, `0b101`
+    fn is_number(value: &str) -> bool {
+        let value = value.strip_prefix('-').unwrap_or(value);
+
+        let is_digits = |digits: &str, radix: u32| {
+            matches!(digits.chars().next(), Some(char) if char.is_digit(radix)) &&
+            digits.chars().all(|char| char.is_digit(radix) || char == '_')
+        };
+
+        match value.get(..2) {
+            Some("0x") => is_digits(&value[2..], 16),
+            Some("0o") => is_digits(&value[2..], 8),
+            Some("0b") => is_digits(&value[2..], 2),
+
+            _ => match value.split_once('.') {
+                Some((integer, fraction)) => is_digits(integer, 10) && is_digits(fraction, 10),
+                None => is_digits(value, 10)
             }
         }
-
-        tokens
     }
 }
@@ -1,11 +1,12 @@
 use crate::parser::prelude::*;
 use crate::ast::entries::prelude::*;
+use crate::ast::entry::Entry;
 
 #[test]
 fn check_tokenizing_error() {
     let tree = Parser::parse("\"wrong string format");
 
-    if let Err(ParseError::TokenizeError(_)) = tree { assert!(true); } else { assert!(false); }
+    assert!(matches!(tree, Err(ParseError::TokenizeError(_))));
 }
 
 #[test]
@@ -16,23 +17,96 @@ fn check_use_statement() {
 
     let tree = tree.unwrap();
 
-    assert_eq!(tree.root.requirements[0], Requirement::Libadwaita(String::from("1.0")));
-    assert_eq!(tree.root.requirements[1], Requirement::Gtk(String::from("4.0")));
+    assert_eq!(tree.root.requirements[0], Requirement::Libadwaita(Version::new(1, 0)));
+    assert_eq!(tree.root.requirements[1], Requirement::Gtk(Version::new(4, 0)));
 
-    assert_eq!(tree.get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><requires lib=\"gtk\" version=\"4.0\"/></interface>"));
+    assert_eq!(tree.get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><requires lib=\"libadwaita\" version=\"1.0\"/><requires lib=\"gtk\" version=\"4.0\"/></interface>"));
+
+    let tree = Parser::parse("using Adw 1; using Gtk 4.0; using Gio 2.0; Adw.Bin {} Box {}");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><requires lib=\"libadwaita\" version=\"1.0\"/><requires lib=\"gtk\" version=\"4.0\"/><object class=\"AdwBin\"></object><object class=\"GtkBox\"></object></interface>"));
 }
 
 #[test]
 fn check_use_statement_error() {
     let tree = Parser::parse("using [] 1.0");
 
-    if let Err(ParseError::IncorrectUseStatement { .. }) = tree {
-        assert!(true);
-    }
-    
-    else {
-        assert!(false);
-    }
+    assert!(matches!(tree, Err(ParseError::IncorrectUseStatement { .. })));
+
+    let tree = Parser::parse("using Gtk 4.0.1;");
+
+    assert!(matches!(tree, Err(ParseError::TokenizeError(TokenizeError::IncorrectNumber { begin: 10, end: 14, .. }))));
+
+    let tree = Parser::parse("using Gtk 4.0; using Gtk 4.0;");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectUseStatement { offset: 15, .. })));
+
+    let tree = Parser::parse("using Adw 1; using Adw 2;");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectUseStatement { offset: 13, .. })));
+
+    let tree = Parser::parse("Gtk.Box {} using Gtk 4.0;");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectUseStatement { offset: 11, .. })));
+
+    let tree = Parser::parse("using Gtk 4.a;");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectUseStatement { offset: 11, .. })));
+
+    let tree = Parser::parse("using adw 1; using Adw 1;");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectUseStatement { offset: 13, .. })));
+}
+
+#[test]
+fn check_namespaces() {
+    let tree = Parser::parse("using Gio 2.0; Gtk.FileDialog { default-filter: Gtk.FileFilter {}; } Gio.Menu {} Gtk.ColumnView { model: typeof<Gio.ListStore>; }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkFileDialog\"><property name=\"default-filter\"><object class=\"GtkFileFilter\"></object></property></object><object class=\"GMenu\"></object><object class=\"GtkColumnView\"><property name=\"model\">GListStore</property></object></interface>"));
+}
+
+#[test]
+fn check_namespaces_error() {
+    let tree = Parser::parse("Gtk.Box { Adw.Bin {} }");
+
+    assert!(matches!(tree, Err(ParseError::UndefinedNamespace { offset: 10, .. })));
+
+    let tree = Parser::parse("template $MyWidget : Adw.Bin {}");
+
+    assert!(matches!(tree, Err(ParseError::UndefinedNamespace { offset: 21, .. })));
+
+    let tree = Parser::parse("Gtk.ListView { item-type: typeof<Gio.File>; }");
+
+    assert!(matches!(tree, Err(ParseError::UndefinedNamespace { offset: 33, .. })));
+}
+
+#[test]
+fn check_translation_domain() {
+    let tree = Parser::parse("using Gtk 4.0; translation-domain \"my&app\"; Gtk.Label { label: _(\"Hi\"); }");
+
+    assert!(tree.is_ok());
+
+    let tree = tree.unwrap();
+
+    assert_eq!(tree.root.translation_domain, Some(String::from("my&app")));
+    assert_eq!(tree.get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface domain=\"my&amp;app\"><requires lib=\"gtk\" version=\"4.0\"/><object class=\"GtkLabel\"><property name=\"label\" translatable=\"yes\">Hi</property></object></interface>"));
+}
+
+#[test]
+fn check_translation_domain_error() {
+    let tree = Parser::parse("Gtk.Box {} translation-domain \"myapp\";");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectTranslationDomain { offset: 11, .. })));
+
+    let tree = Parser::parse("translation-domain \"a\"; translation-domain \"b\";");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectTranslationDomain { offset: 24, .. })));
+
+    let tree = Parser::parse("translation-domain myapp;");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectTranslationDomain { offset: 0, .. })));
 }
 
 #[test]
@@ -52,15 +126,15 @@ fn check_object_definition() {
 fn check_object_definition_error() {
     let tree = Parser::parse("Gtk.Button");
 
-    if let Err(ParseError::IncorrectObjectDefinition { .. }) = tree { assert!(true); } else { assert!(false); }
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { .. })));
 
     let tree = Parser::parse("Gtk.Button button_name");
 
-    if let Err(ParseError::IncorrectObjectDefinition { .. }) = tree { assert!(true); } else { assert!(false); }
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { .. })));
 
     let tree = Parser::parse("Gtk.Button button name");
 
-    if let Err(ParseError::IncorrectObjectDefinition { .. }) = tree { assert!(true); } else { assert!(false); }
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { .. })));
 }
 
 #[test]
@@ -80,11 +154,11 @@ fn check_properties() {
 fn check_properties_error() {
     let tree = Parser::parse("example-property:");
 
-    if let Err(ParseError::IncorrectPropertyDefinition { .. }) = tree { assert!(true); } else { assert!(false); }
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { .. })));
 
     let tree = Parser::parse("example-property: example-value");
 
-    if let Err(ParseError::IncorrectPropertyDefinition { .. }) = tree { assert!(true); } else { assert!(false); }
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { .. })));
 }
 
 #[test]
@@ -121,5 +195,810 @@ fn check_complex_parsing() {
     ");
 
     assert!(tree.is_ok());
-    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><requires lib=\"gtk\" version=\"4.0\"/><object class=\"AdwApplicationWindow\" id=\"window\"><property name=\"default-width\">600</property><property name=\"default-height\">500</property><property name=\"content\"><object class=\"GtkBox\"><property name=\"orientation\">vertical</property><child><object class=\"AdwHeaderBar\"><property name=\"title-widget\"><object class=\"AdwWindowTitle\"><property name=\"title\">Example app</property></object></property></object></child><child><object class=\"AdwPreferencesPage\"><child><object class=\"AdwPreferencesGroup\"><property name=\"vexpand\">true</property><property name=\"valign\">center</property><child><object class=\"GtkButton\"><property name=\"label\">Hello, World!</property></object></child></object></child></object></child></object></property></object></interface>"));
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><requires lib=\"gtk\" version=\"4.0\"/><requires lib=\"libadwaita\" version=\"1.0\"/><object class=\"AdwApplicationWindow\" id=\"window\"><property name=\"default-width\">600</property><property name=\"default-height\">500</property><property name=\"content\"><object class=\"GtkBox\"><property name=\"orientation\">vertical</property><child><object class=\"AdwHeaderBar\"><property name=\"title-widget\"><object class=\"AdwWindowTitle\"><property name=\"title\">Example app</property></object></property></object></child><child><object class=\"AdwPreferencesPage\"><child><object class=\"AdwPreferencesGroup\"><property name=\"vexpand\">true</property><property name=\"valign\">center</property><child><object class=\"GtkButton\"><property name=\"label\">Hello, World!</property></object></child></object></child></object></child></object></property></object></interface>"));
+}
+
+#[test]
+fn check_translatable_properties() {
+    let tree = Parser::parse("label: _(\"Hello\");");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><property name=\"label\" translatable=\"yes\">Hello</property></interface>"));
+
+    let tree = Parser::parse("label: C_(\"greeting\", \"Hello & bye\");");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><property name=\"label\" translatable=\"yes\" context=\"greeting\">Hello &amp; bye</property></interface>"));
+
+    let tree = Parser::parse("Gtk.Label { label: /* Translators: shown on startup */ _(\"Hello\"); }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkLabel\"><property name=\"label\" translatable=\"yes\" comments=\"Translators: shown on startup\">Hello</property></object></interface>"));
+}
+
+#[test]
+fn check_translatable_properties_error() {
+    let tree = Parser::parse("label: _(\"Hello\", \"World\");");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectSyntax { .. })));
+
+    let tree = Parser::parse("label: C_(\"Hello\");");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectSyntax { .. })));
+}
+
+#[test]
+fn check_bindings() {
+    let tree = Parser::parse("Gtk.Switch switch {} Gtk.Button { sensitive: bind switch.active; }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkSwitch\" id=\"switch\"></object><object class=\"GtkButton\"><property name=\"sensitive\" bind-source=\"switch\" bind-property=\"active\" bind-flags=\"sync-create\"/></object></interface>"));
+
+    let tree = Parser::parse("Gtk.Button { sensitive: bind switch.active inverted bidirectional; } Gtk.Switch switch {}");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkButton\"><property name=\"sensitive\" bind-source=\"switch\" bind-property=\"active\" bind-flags=\"sync-create|bidirectional|invert-boolean\"/></object><object class=\"GtkSwitch\" id=\"switch\"></object></interface>"));
+
+    let tree = Parser::parse("Gtk.Switch switch {} Gtk.Button { sensitive: bind switch.active no-sync-create ; }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkSwitch\" id=\"switch\"></object><object class=\"GtkButton\"><property name=\"sensitive\" bind-source=\"switch\" bind-property=\"active\"/></object></interface>"));
+
+    let tree = Parser::parse("template $MyRow: Gtk.Box { Gtk.Label { label: bind template.item as <Gtk.StringObject>.string; } }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><template class=\"MyRow\" parent=\"GtkBox\"><child><object class=\"GtkLabel\"><binding name=\"label\"><lookup name=\"string\" type=\"GtkStringObject\"><lookup name=\"item\"><constant>template</constant></lookup></lookup></binding></object></child></template></interface>"));
+}
+
+#[test]
+fn check_bindings_error() {
+    let tree = Parser::parse("Gtk.Button { sensitive: bind switch.active; }");
+
+    assert_eq!(tree.unwrap_err(), ParseError::UndefinedObject {
+        message: String::from("Undefined object \"switch\" referenced at offset 29"),
+        offset: 29
+    });
+
+    let tree = Parser::parse("Gtk.Switch switch {} Gtk.Button { sensitive: bind switch.active wrong-flag; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { .. })));
+
+    let tree = Parser::parse("Gtk.Switch switch {} Gtk.Button { sensitive: bind switch; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { .. })));
+
+    let tree = Parser::parse("template $MyRow: Gtk.Box { Gtk.Label { label: bind template.item as <Gtk.StringObject>.string inverted; } }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { offset: 94, .. })));
+
+    let tree = Parser::parse("template $MyRow: Gtk.Box { Gtk.Label { label: bind template.item as <Gtk.StringObject>; } }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExpression { offset: 86, .. })));
+}
+
+#[test]
+fn check_expressions() {
+    let tree = Parser::parse("Gtk.DropDown { expression: expr item as <Gtk.StringObject>.string; }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkDropDown\"><property name=\"expression\"><lookup name=\"string\" type=\"GtkStringObject\"/></property></object></interface>"));
+
+    let tree = Parser::parse("Gtk.Entry my_entry {} Gtk.Label { label: expr $format(my_entry.text, \"Hi\", 0x0C) as <string>; }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkEntry\" id=\"my_entry\"></object><object class=\"GtkLabel\"><property name=\"label\"><closure type=\"gchararray\" function=\"format\"><lookup name=\"text\"><constant>my_entry</constant></lookup><constant type=\"gchararray\">Hi</constant><constant type=\"gint\">12</constant></closure></property></object></interface>"));
+
+    let tree = Parser::parse("template $MyRow: Gtk.Box { Gtk.Label { label: expr template.item as <$MyItem>.name; } }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><template class=\"MyRow\" parent=\"GtkBox\"><child><object class=\"GtkLabel\"><property name=\"label\"><lookup name=\"name\" type=\"MyItem\"><lookup name=\"item\"><constant>template</constant></lookup></lookup></property></object></child></template></interface>"));
+}
+
+#[test]
+fn check_expressions_error() {
+    let tree = Parser::parse("Gtk.Label { label: expr $format(item.name); }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExpression { .. })));
+
+    let tree = Parser::parse("Gtk.Label { label: expr item as <Gtk.StringObject>; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExpression { .. })));
+
+    let tree = Parser::parse("Gtk.Label { label: expr item as <Gtk.StringObject>.; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExpression { offset: 51, .. })));
+
+    let tree = Parser::parse("Gtk.Label { label: expr item; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExpression { offset: 24, .. })));
+
+    let tree = Parser::parse("Gtk.Label { label: expr my_entry.text; }");
+
+    assert!(matches!(tree, Err(ParseError::UndefinedObject { offset: 24, .. })));
+
+    let tree = Parser::parse("Gtk.Label { label: expr item as <Adw.ActionRow>.title; }");
+
+    assert!(matches!(tree, Err(ParseError::UndefinedNamespace { offset: 33, .. })));
+
+    let tree = Parser::parse("Gtk.Label { label: expr $format(item as <Gtk.StringObject>.string) as <string> label; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExpression { offset: 79, .. })));
+
+    let tree = Parser::parse("Gtk.Label { label: expr item.name; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExpression { offset: 28, .. })));
+
+    let tree = Parser::parse("Gtk.Label { label: expr $format(item) as <string>; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExpression { offset: 32, .. })));
+}
+
+#[test]
+fn check_signals() {
+    let tree = Parser::parse("Gtk.Button { clicked => $on_clicked() swapped after; label: \"Hi\"; }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkButton\"><signal name=\"clicked\" handler=\"on_clicked\" swapped=\"yes\" after=\"yes\"/><property name=\"label\">Hi</property></object></interface>"));
+
+    let tree = Parser::parse("Gtk.Label other_obj {} Gtk.Button { notify::label => $on_notify(other_obj); }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkLabel\" id=\"other_obj\"></object><object class=\"GtkButton\"><signal name=\"notify::label\" handler=\"on_notify\" object=\"other_obj\"/></object></interface>"));
+}
+
+#[test]
+fn check_signals_error() {
+    let tree = Parser::parse("Gtk.Button { clicked => $on_clicked() }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectEventDefinition { .. })));
+
+    let tree = Parser::parse("Gtk.Button { clicked => $on_clicked() before; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectEventDefinition { .. })));
+
+    let tree = Parser::parse("Gtk.Button { clicked => $on_clicked(other_obj); }");
+
+    assert!(matches!(tree, Err(ParseError::UndefinedObject { offset: 36, .. })));
+}
+
+#[test]
+fn check_templates() {
+    let tree = Parser::parse("template $MyWidget : Gtk.Box { spacing: 6; Gtk.Label label {} }");
+
+    assert!(tree.is_ok());
+
+    let tree = tree.unwrap();
+
+    assert_eq!(tree.get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><template class=\"MyWidget\" parent=\"GtkBox\"><property name=\"spacing\">6</property><child><object class=\"GtkLabel\" id=\"label\"></object></child></template></interface>"));
+
+    let template = tree.root.get_template().unwrap();
+
+    assert_eq!(template.class, TypeName::parse("$MyWidget").unwrap());
+    assert_eq!(template.parent, TypeName::parse("Gtk.Box"));
+
+    let names = tree.root.get_named_objects().into_iter().map(|(name, _)| name).collect::<Vec<String>>();
+
+    assert_eq!(names, vec![String::from("template"), String::from("label")]);
+
+    let tree = Parser::parse("template $MyWidget: Gtk.Box { Gtk.Label { visible: bind template.visible; } }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><template class=\"MyWidget\" parent=\"GtkBox\"><child><object class=\"GtkLabel\"><property name=\"visible\" bind-source=\"template\" bind-property=\"visible\" bind-flags=\"sync-create\"/></object></child></template></interface>"));
+
+    let tree = Parser::parse("template $MyWidget : Box {}");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><template class=\"MyWidget\" parent=\"GtkBox\"></template></interface>"));
+}
+
+#[test]
+fn check_templates_error() {
+    let tree = Parser::parse("template $MyWidget : {}");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { .. })));
+
+    let tree = Parser::parse("template $MyWidget : Gtk.Box");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { .. })));
+
+    let tree = Parser::parse("template $MyWidget : Gtk.Box {} template $MyOther : Gtk.Box {}");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { offset: 32, .. })));
+
+    let tree = Parser::parse("Gtk.Box { template $MyWidget : Gtk.Box {} }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { offset: 10, .. })));
+
+    let tree = Parser::parse("template $MyWidget : Gtk.Box { template $MyOther : Gtk.Box {} }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { offset: 31, .. })));
+
+    let tree = Parser::parse("Gtk.ListView { factory: Gtk.BuilderListItemFactory { template ListItem {} template ListItem {} }; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { offset: 74, .. })));
+}
+
+#[test]
+fn check_menus() {
+    let tree = Parser::parse("
+        menu primary_menu {
+            section {
+                label: \"Actions\";
+
+                item {
+                    label: _(\"Quit\");
+                    action: \"app.quit\";
+                }
+
+                item (_(\"About\"), \"app.about\", \"help-about-symbolic\")
+            }
+
+            submenu {
+                label: \"More\";
+
+                item (\"Preferences\")
+            }
+        }
+    ");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><menu id=\"primary_menu\"><section><attribute name=\"label\">Actions</attribute><item><attribute name=\"label\" translatable=\"yes\">Quit</attribute><attribute name=\"action\">app.quit</attribute></item><item><attribute name=\"label\" translatable=\"yes\">About</attribute><attribute name=\"action\">app.about</attribute><attribute name=\"verb-icon\">help-about-symbolic</attribute></item></section><submenu><attribute name=\"label\">More</attribute><item><attribute name=\"label\">Preferences</attribute></item></submenu></menu></interface>"));
+
+    let tree = Parser::parse("Gtk.MenuButton { menu-model: menu { item (\"Quit\", \"app.quit\") }; }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkMenuButton\"><property name=\"menu-model\">__menu_1</property></object><menu id=\"__menu_1\"><item><attribute name=\"label\">Quit</attribute><attribute name=\"action\">app.quit</attribute></item></menu></interface>"));
+}
+
+#[test]
+fn check_menus_error() {
+    let tree = Parser::parse("section {}");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectMenuDefinition { .. })));
+
+    let tree = Parser::parse("menu { menu {} }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectMenuDefinition { .. })));
+
+    let tree = Parser::parse("Gtk.Box { menu {} }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectMenuDefinition { .. })));
+
+    let tree = Parser::parse("menu { item (\"Quit\", \"app.quit\", \"icon\", \"extra\") }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectMenuDefinition { .. })));
+
+    let tree = Parser::parse("menu { item { section {} } }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectMenuDefinition { .. })));
+}
+
+#[test]
+fn check_styles() {
+    let tree = Parser::parse("using Adw 1; Adw.PreferencesGroup { styles [\"card\", \"boxed-list\"] Gtk.Label { styles [\"dim-label\",] } }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><requires lib=\"libadwaita\" version=\"1.0\"/><object class=\"AdwPreferencesGroup\"><child><object class=\"GtkLabel\"><style><class name=\"dim-label\"/></style></object></child><style><class name=\"card\"/><class name=\"boxed-list\"/></style></object></interface>"));
+}
+
+#[test]
+fn check_styles_error() {
+    let tree = Parser::parse("Gtk.Box { styles [card] }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { offset: 18, .. })));
+
+    let tree = Parser::parse("styles [\"card\"]");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { .. })));
+}
+
+#[test]
+fn check_layout() {
+    let tree = Parser::parse("Gtk.Grid { Gtk.Button { label: \"Hi\"; layout { column: 1; row: 2; } } }");
+
+    assert!(tree.is_ok());
+
+    let tree = tree.unwrap();
+
+    assert_eq!(tree.get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkGrid\"><child><object class=\"GtkButton\"><property name=\"label\">Hi</property><layout><property name=\"column\">1</property><property name=\"row\">2</property></layout></object></child></object></interface>"));
+
+    assert!(tree.root.dbg().contains("Layout {"));
+}
+
+#[test]
+fn check_layout_error() {
+    let tree = Parser::parse("Gtk.Grid { Gtk.Button { layout { Gtk.Label {} } } }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { .. })));
+}
+
+#[test]
+fn check_accessibility() {
+    let tree = Parser::parse("Gtk.Box { Gtk.Label title {} Gtk.Button { accessibility { label: _(\"Close\"); labelled-by: title; checked: true; } } }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkBox\"><child><object class=\"GtkLabel\" id=\"title\"></object></child><child><object class=\"GtkButton\"><accessibility><property name=\"label\" translatable=\"yes\">Close</property><relation name=\"labelled-by\">title</relation><state name=\"checked\">true</state></accessibility></object></child></object></interface>"));
+}
+
+#[test]
+fn check_accessibility_error() {
+    let tree = Parser::parse("Gtk.Button { accessibility { colour: red; } }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { offset: 29, .. })));
+
+    let tree = Parser::parse("Gtk.Button { accessibility { labelled-by: title; } }");
+
+    assert!(matches!(tree, Err(ParseError::UndefinedObject { offset: 42, .. })));
+}
+
+#[test]
+fn check_child_types() {
+    let tree = Parser::parse("using Adw 1; Gtk.Window { [titlebar] Adw.HeaderBar { [start] Gtk.Button {} } Gtk.Box {} }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><requires lib=\"libadwaita\" version=\"1.0\"/><object class=\"GtkWindow\"><child type=\"titlebar\"><object class=\"AdwHeaderBar\"><child type=\"start\"><object class=\"GtkButton\"></object></child></object></child><child><object class=\"GtkBox\"></object></child></object></interface>"));
+
+    let tree = Parser::parse("Gtk.Dialog { [internal-child content_area] Gtk.Box { spacing: 6; } }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkDialog\"><child internal-child=\"content_area\"><object class=\"GtkBox\"><property name=\"spacing\">6</property></object></child></object></interface>"));
+}
+
+#[test]
+fn check_child_types_error() {
+    let tree = Parser::parse("Gtk.Window { [titlebar] spacing: 6; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { offset: 13, .. })));
+
+    let tree = Parser::parse("Gtk.Window { [titlebar] }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { offset: 13, .. })));
+
+    let tree = Parser::parse("Gtk.Window { [internal-child] Gtk.Box {} }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { .. })));
+
+    let tree = Parser::parse("[titlebar] Gtk.Box {}");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { .. })));
+}
+
+#[test]
+fn check_action_widgets() {
+    let tree = Parser::parse("Gtk.Dialog { [action response=ok default] Gtk.Button ok_button {} [action response = -5] Gtk.Button {} }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkDialog\"><child type=\"action\"><object class=\"GtkButton\" id=\"ok_button\"></object></child><child type=\"action\"><object class=\"GtkButton\" id=\"__action_widget_1\"></object></child><action-widgets><action-widget response=\"ok\" default=\"yes\">ok_button</action-widget><action-widget response=\"-5\">__action_widget_1</action-widget></action-widgets></object></interface>"));
+}
+
+#[test]
+fn check_action_widgets_error() {
+    let tree = Parser::parse("Gtk.Dialog { [action default] Gtk.Button {} }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { .. })));
+
+    let tree = Parser::parse("Gtk.Dialog { [action result=ok] Gtk.Button {} }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { .. })));
+}
+
+#[test]
+fn check_strings_and_items() {
+    let tree = Parser::parse("Gtk.DropDown { model: Gtk.StringList { strings [\"Light\", _(\"Dark\")] }; }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkDropDown\"><property name=\"model\"><object class=\"GtkStringList\"><items><item>Light</item><item translatable=\"yes\">Dark</item></items></object></property></object></interface>"));
+
+    let tree = Parser::parse("Gtk.ComboBoxText { items [light: \"Light\", dark: C_(\"theme\", \"Dark\"), \"System\"] }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkComboBoxText\"><items><item id=\"light\">Light</item><item id=\"dark\" translatable=\"yes\" context=\"theme\">Dark</item><item>System</item></items></object></interface>"));
+}
+
+#[test]
+fn check_strings_and_items_error() {
+    let tree = Parser::parse("Gtk.Box { strings [\"a\"] }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { offset: 10, .. })));
+
+    let tree = Parser::parse("Gtk.StringList { items [\"a\"] }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { .. })));
+
+    let tree = Parser::parse("Gtk.StringList { strings [a] }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { offset: 26, .. })));
+}
+
+#[test]
+fn check_attributes() {
+    let tree = Parser::parse("Gtk.Label { label: \"Title\"; attributes { weight: bold; scale: 1.2; family: \"Sans & Serif\"; } }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkLabel\"><property name=\"label\">Title</property><attributes><attribute name=\"weight\" value=\"bold\"/><attribute name=\"scale\" value=\"1.2\"/><attribute name=\"family\" value=\"Sans &amp; Serif\"/></attributes></object></interface>"));
+}
+
+#[test]
+fn check_attributes_error() {
+    let tree = Parser::parse("Gtk.Label { attributes { weight: bold; colour: red; } }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { offset: 39, .. })));
+
+    let tree = Parser::parse("Gtk.Box { attributes { weight: bold; } }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { .. })));
+}
+
+#[test]
+fn check_size_group() {
+    let tree = Parser::parse("Gtk.Box { Gtk.Label label {} Gtk.Entry entry {} } Gtk.SizeGroup { mode: horizontal; widgets [label, entry] }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkBox\"><child><object class=\"GtkLabel\" id=\"label\"></object></child><child><object class=\"GtkEntry\" id=\"entry\"></object></child></object><object class=\"GtkSizeGroup\"><property name=\"mode\">horizontal</property><widgets><widget name=\"label\"/><widget name=\"entry\"/></widgets></object></interface>"));
+}
+
+#[test]
+fn check_size_group_error() {
+    let tree = Parser::parse("Gtk.Label label {} Gtk.SizeGroup { widgets [label, entry] }");
+
+    assert!(matches!(tree, Err(ParseError::UndefinedObject { offset: 51, .. })));
+
+    let tree = Parser::parse("Gtk.Label label {} Gtk.SizeGroup { widgets [label, \"entry\"] }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { offset: 51, .. })));
+
+    let tree = Parser::parse("Gtk.Label label {} Gtk.Box { widgets [label] }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { .. })));
+}
+
+#[test]
+fn check_file_filter() {
+    let tree = Parser::parse("Gtk.FileFilter { name: \"Images\"; mime-types [\"image/png\", \"image/*\"] patterns [\"*.svg\"] suffixes [\"jpg\", \"jpeg\"] }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkFileFilter\"><property name=\"name\">Images</property><mime-types><mime-type>image/png</mime-type><mime-type>image/*</mime-type></mime-types><patterns><pattern>*.svg</pattern></patterns><suffixes><suffix>jpg</suffix><suffix>jpeg</suffix></suffixes></object></interface>"));
+}
+
+#[test]
+fn check_file_filter_error() {
+    let tree = Parser::parse("Gtk.FileFilter { patterns [\"*.svg\", txt] }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { offset: 36, .. })));
+
+    let tree = Parser::parse("Gtk.Box { suffixes [\"jpg\"] }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { .. })));
+}
+
+#[test]
+fn check_list_item_factory() {
+    let tree = Parser::parse("Gtk.ListView { factory: Gtk.BuilderListItemFactory { template ListItem { child: Gtk.Label { label: bind template.position; }; } }; }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkListView\"><property name=\"factory\"><object class=\"GtkBuilderListItemFactory\"><property name=\"bytes\"><![CDATA[<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><template class=\"GtkListItem\"><property name=\"child\"><object class=\"GtkLabel\"><property name=\"label\" bind-source=\"template\" bind-property=\"position\" bind-flags=\"sync-create\"/></object></property></template></interface>]]></property></object></property></object></interface>"));
+
+    let tree = Parser::parse("Gtk.ListView { factory: Gtk.BuilderListItemFactory { template ListItem { child: Gtk.MenuButton button { menu-model: menu { item (\"A\", \"app.a\") }; }; } }; }");
+
+    assert!(tree.is_ok());
+
+    let tree = tree.unwrap();
+
+    assert!(tree.root.get_named_objects().is_empty());
+    assert_eq!(tree.get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkListView\"><property name=\"factory\"><object class=\"GtkBuilderListItemFactory\"><property name=\"bytes\"><![CDATA[<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><template class=\"GtkListItem\"><property name=\"child\"><object class=\"GtkMenuButton\" id=\"button\"><property name=\"menu-model\">__menu_1</property></object></property></template><menu id=\"__menu_1\"><item><attribute name=\"label\">A</attribute><attribute name=\"action\">app.a</attribute></item></menu></interface>]]></property></object></property></object></interface>"));
+
+    let tree = Parser::parse("Gtk.Label label {} Gtk.ListView { factory: Gtk.BuilderListItemFactory { template ListItem { child: Gtk.Box { accessible-role: label; }; } }; }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkLabel\" id=\"label\"></object><object class=\"GtkListView\"><property name=\"factory\"><object class=\"GtkBuilderListItemFactory\"><property name=\"bytes\"><![CDATA[<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><template class=\"GtkListItem\"><property name=\"child\"><object class=\"GtkBox\"><property name=\"accessible-role\">label</property></object></property></template></interface>]]></property></object></property></object></interface>"));
+
+    let tree = Parser::parse("translation-domain \"app\"; Gtk.ListView { factory: Gtk.BuilderListItemFactory { template ListItem { child: Gtk.Label { label: _(\"Item\"); }; } }; }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface domain=\"app\"><object class=\"GtkListView\"><property name=\"factory\"><object class=\"GtkBuilderListItemFactory\"><property name=\"bytes\"><![CDATA[<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface domain=\"app\"><template class=\"GtkListItem\"><property name=\"child\"><object class=\"GtkLabel\"><property name=\"label\" translatable=\"yes\">Item</property></object></property></template></interface>]]></property></object></property></object></interface>"));
+}
+
+#[test]
+fn check_list_item_factory_error() {
+    let tree = Parser::parse("Gtk.ListView { factory: Gtk.BuilderListItemFactory { template ListItem { child: Gtk.Label { label: bind item.name; }; } }; }");
+
+    assert!(matches!(tree, Err(ParseError::UndefinedObject { .. })));
+
+    let tree = Parser::parse("Gtk.ListView { factory: Gtk.BuilderListItemFactory { template ListItem }; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { .. })));
+
+    let tree = Parser::parse("Gtk.Entry outer {} Gtk.ListView { factory: Gtk.BuilderListItemFactory { template ListItem { child: Gtk.Label { label: bind outer.text; }; } }; }");
+
+    assert!(matches!(tree, Err(ParseError::UndefinedObject { offset: 123, .. })));
+
+    let tree = Parser::parse("Gtk.ListView { factory: Gtk.BuilderListItemFactory { template ListItem { child: Gtk.Button { clicked => $on_clicked(outer); }; } }; } Gtk.Entry outer {}");
+
+    assert!(matches!(tree, Err(ParseError::UndefinedObject { offset: 116, .. })));
+}
+
+#[test]
+fn check_breakpoint() {
+    let tree = Parser::parse("using Adw 1; Adw.Window { Adw.NavigationSplitView split_view {} Adw.Breakpoint { condition (\"max-width: 500sp\") setters { split_view.collapsed: true; split_view.tooltip-text: _(\"Narrow\"); } } }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><requires lib=\"libadwaita\" version=\"1.0\"/><object class=\"AdwWindow\"><child><object class=\"AdwNavigationSplitView\" id=\"split_view\"></object></child><child><object class=\"AdwBreakpoint\"><condition>max-width: 500sp</condition><setter object=\"split_view\" property=\"collapsed\">true</setter><setter object=\"split_view\" property=\"tooltip-text\" translatable=\"yes\">Narrow</setter></object></child></object></interface>"));
+}
+
+#[test]
+fn check_breakpoint_error() {
+    let tree = Parser::parse("using Adw 1; Adw.Breakpoint { setters { split_view.collapsed: true; } }");
+
+    assert!(matches!(tree, Err(ParseError::UndefinedObject { offset: 40, .. })));
+
+    let tree = Parser::parse("using Adw 1; Adw.Breakpoint { setters { collapsed: true; } }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { offset: 40, .. })));
+
+    let tree = Parser::parse("using Adw 1; Adw.Breakpoint { condition (max-width) }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { offset: 40, .. })));
+
+    let tree = Parser::parse("Gtk.Box { condition (\"max-width: 500sp\") }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { .. })));
+}
+
+#[test]
+fn check_responses() {
+    let tree = Parser::parse("using Adw 1; Adw.MessageDialog { heading: _(\"Delete file?\"); responses [cancel: _(\"Cancel\"), delete: C_(\"file\", \"Delete\") destructive, ok: \"OK\" suggested disabled,] }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><requires lib=\"libadwaita\" version=\"1.0\"/><object class=\"AdwMessageDialog\"><property name=\"heading\" translatable=\"yes\">Delete file?</property><responses><response id=\"cancel\" translatable=\"yes\">Cancel</response><response id=\"delete\" appearance=\"destructive\" translatable=\"yes\" context=\"file\">Delete</response><response id=\"ok\" appearance=\"suggested\" enabled=\"false\">OK</response></responses></object></interface>"));
+}
+
+#[test]
+fn check_responses_error() {
+    let tree = Parser::parse("using Adw 1; Adw.AlertDialog { responses [ok: \"OK\" primary] }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { offset: 51, .. })));
+
+    let tree = Parser::parse("using Adw 1; Adw.AlertDialog { responses [ok: \"OK\" suggested destructive] }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { offset: 61, .. })));
+
+    let tree = Parser::parse("using Adw 1; Adw.AlertDialog { responses [\"OK\"] }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { offset: 42, .. })));
+
+    let tree = Parser::parse("Gtk.Box { responses [ok: \"OK\"] }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectExtensionDefinition { .. })));
+}
+
+#[test]
+fn check_extern_types() {
+    let tree = Parser::parse("using Adw 1; Adw.NavigationSplitView { sidebar: $MyAppSidebar sidebar {}; }");
+
+    assert!(tree.is_ok());
+
+    let tree = tree.unwrap();
+
+    assert_eq!(tree.get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><requires lib=\"libadwaita\" version=\"1.0\"/><object class=\"AdwNavigationSplitView\"><property name=\"sidebar\"><object class=\"MyAppSidebar\" id=\"sidebar\"></object></property></object></interface>"));
+
+    let (_, sidebar) = tree.root.get_named_objects().remove(0);
+
+    assert_eq!(sidebar.class, TypeName {
+        namespace: None,
+        name: String::from("MyAppSidebar"),
+        external: true
+    });
+}
+
+#[test]
+fn check_extern_types_error() {
+    let tree = Parser::parse("Gtk.Box { $ {} }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { offset: 10, .. })));
+
+    let tree = Parser::parse("Gtk.Box { Gtk.Widgets.Label {} }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectObjectDefinition { offset: 10, .. })));
+}
+
+#[test]
+fn check_typed_properties() {
+    let tree = Parser::parse("Gtk.StringList strings {} Gtk.DropDown { spacing: 12; scale: -1.5; visible: true; halign: start; edges: top | bottom; model: strings; tooltip-text: \"a < b\"; selected-item: null; item-type: typeof<Gtk.StringObject>; }");
+
+    assert!(tree.is_ok());
+
+    let tree = tree.unwrap();
+
+    assert_eq!(tree.get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkStringList\" id=\"strings\"></object><object class=\"GtkDropDown\"><property name=\"spacing\">12</property><property name=\"scale\">-1.5</property><property name=\"visible\">true</property><property name=\"halign\">start</property><property name=\"edges\">top|bottom</property><property name=\"model\">strings</property><property name=\"tooltip-text\">a &lt; b</property><property name=\"selected-item\"></property><property name=\"item-type\">GtkStringObject</property></object></interface>"));
+
+    let Some(Entry::Object(drop_down)) = tree.root.children.get(1) else {
+        panic!("Object expected");
+    };
+
+    let values = drop_down.children.iter().filter_map(|child| match child {
+        Entry::Property(property) => Some(property.value.clone()),
+        _ => None
+    }).collect::<Vec<PropertyValue>>();
+
+    assert_eq!(values, vec![
+        PropertyValue::Int(12),
+        PropertyValue::Float(-1.5),
+        PropertyValue::Bool(true),
+        PropertyValue::Ident(String::from("start")),
+        PropertyValue::Flags(vec![String::from("top"), String::from("bottom")]),
+        PropertyValue::ObjectRef(String::from("strings")),
+        PropertyValue::String(String::from("a < b")),
+        PropertyValue::Null,
+        PropertyValue::TypeName(TypeName::parse("Gtk.StringObject").unwrap())
+    ]);
+}
+
+#[test]
+fn check_typed_properties_error() {
+    let tree = Parser::parse("Gtk.Box { spacing: 12px; }");
+
+    assert!(matches!(tree, Err(ParseError::TokenizeError(TokenizeError::IncorrectNumber { begin: 19, end: 22, .. }))));
+
+    let tree = Parser::parse("Gtk.Box { edges: top | 12; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { offset: 17, .. })));
+
+    let tree = Parser::parse("Gtk.Box { item-type: typeof<Gtk.>; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { offset: 21, .. })));
+}
+
+#[test]
+fn check_comments() {
+    let tree = Parser::parse("
+        // Main window
+        Gtk.Window {
+            /* Title of the window */
+            title: /* Translators: window title */ _(\"Hello\"); // trailing comment
+            width-request: 300; /* ; } */
+
+            child: Gtk.Label {
+                label: expr item as <Gtk.StringObject> /* the item */.string;
+            };
+        }
+    ");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkWindow\"><property name=\"title\" translatable=\"yes\" comments=\"Translators: window title\">Hello</property><property name=\"width-request\">300</property><property name=\"child\"><object class=\"GtkLabel\"><property name=\"label\"><lookup name=\"string\" type=\"GtkStringObject\"/></property></object></property></object></interface>"));
+}
+
+#[test]
+fn check_comments_error() {
+    let tree = Parser::parse("Gtk.Window { /* title: \"Hello\"; }");
+
+    assert!(matches!(tree, Err(ParseError::TokenizeError(_))));
+}
+
+#[test]
+fn check_string_escapes() {
+    let tree = Parser::parse("Gtk.Label { label: \"Say \\\"hi\\\"\" ' & bye'; tooltip-text: _('It\\'s'); accessibility { description: expr $format(\"\\u00e9\") as <string>; } }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkLabel\"><property name=\"label\">Say &quot;hi&quot; &amp; bye</property><property name=\"tooltip-text\" translatable=\"yes\">It&apos;s</property><accessibility><property name=\"description\"><closure type=\"gchararray\" function=\"format\"><constant type=\"gchararray\">\u{e9}</constant></closure></property></accessibility></object></interface>"));
+}
+
+#[test]
+fn check_string_escapes_error() {
+    let tree = Parser::parse("Gtk.Label { label: _(\"\\x\"); }");
+
+    assert!(matches!(tree, Err(ParseError::TokenizeError(TokenizeError::IncorrectString { begin: 22, .. }))));
+
+    let tree = Parser::parse("Gtk.Label { label: expr $f(\"\\q\") as <string>; }");
+
+    assert!(matches!(tree, Err(ParseError::TokenizeError(TokenizeError::IncorrectString { begin: 28, end: 29, .. }))));
+}
+
+#[test]
+fn check_punctuation_spacing() {
+    let tree = Parser::parse("Gtk.Box { spacing : 6 ; notify :: spacing => $on_spacing ( ) ; [ start ] Gtk . Label { label : 'Hi' ; }; }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkBox\"><signal name=\"notify::spacing\" handler=\"on_spacing\"/><property name=\"spacing\">6</property><child type=\"start\"><object class=\"GtkLabel\"><property name=\"label\">Hi</property></object></child></object></interface>"));
+}
+
+#[test]
+fn check_punctuation_spacing_error() {
+    let tree = Parser::parse("Gtk.Box { spacing: 6 @ }");
+
+    assert!(matches!(tree, Err(ParseError::TokenizeError(TokenizeError::IncorrectChar { offset: 21, .. }))));
+
+    let tree = Parser::parse("Gtk.Box { visible: true | ; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { offset: 19, .. })));
+}
+
+#[test]
+fn check_array_values() {
+    let tree = Parser::parse("using Adw 1; Adw.AboutWindow { developers: [\"A\", \"B & C\",]; artists: []; }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><requires lib=\"libadwaita\" version=\"1.0\"/><object class=\"AdwAboutWindow\"><property name=\"developers\">A\nB &amp; C</property><property name=\"artists\"></property></object></interface>"));
+
+    let tree = Parser::parse("Gtk.AboutDialog { authors: [_(\"Team\"), _(\"A\")]; }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkAboutDialog\"><property name=\"authors\" translatable=\"yes\">Team\nA</property></object></interface>"));
+}
+
+#[test]
+fn check_array_values_error() {
+    let tree = Parser::parse("Gtk.AboutDialog { authors: [\"A\", b]; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { offset: 33, .. })));
+
+    let tree = Parser::parse("Gtk.AboutDialog { authors: [, \"A\"]; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { offset: 27, .. })));
+
+    let tree = Parser::parse("Gtk.AboutDialog { authors: [\"A\", _(\"B\")]; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { offset: 33, .. })));
+
+    let tree = Parser::parse("Gtk.AboutDialog { authors: [C_(\"c\", \"A\"), _(\"B\")]; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { offset: 42, .. })));
+    let tree = Parser::parse("Gtk.AboutDialog { authors: [_(\"A\"), _(\"B\"), \"C\", _(\"D\")]; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { offset: 44, .. })));
+}
+
+#[test]
+fn check_non_ascii_text() {
+    let tree = Parser::parse("Gtk.Box { Gtk.Label { label: \"Привет\"; } Gtk.Label { label: \"é\"; } Gtk.Label { tooltip-text: /* Переводчикам */ _(\"Мир\"); } }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkBox\"><child><object class=\"GtkLabel\"><property name=\"label\">Привет</property></object></child><child><object class=\"GtkLabel\"><property name=\"label\">é</property></object></child><child><object class=\"GtkLabel\"><property name=\"tooltip-text\" translatable=\"yes\" comments=\"Переводчикам\">Мир</property></object></child></object></interface>"));
+}
+
+#[test]
+fn check_non_ascii_text_error() {
+    let tree = Parser::parse("Gtk.Box { Gtk.Label { label: \"Привет\"; } Gtk.Label { visible: true | ; } }");
+
+    match tree {
+        Err(ParseError::IncorrectPropertyDefinition { message, offset: 62 }) => assert!(message.ends_with("offset 62")),
+        _ => panic!("IncorrectPropertyDefinition expected")
+    }
+
+    let tree = Parser::parse("Gtk.Box { Gtk.Label { label: \"é\"; label: bind entry.text; } }");
+
+    assert!(matches!(tree, Err(ParseError::UndefinedObject { offset: 46, .. })));
+}
+
+#[test]
+fn check_number_literals() {
+    let tree = Parser::parse("Gtk.Label { margin-top: 0x1F; margin-bottom: -0o17; margin-start: 0b101; max-width-chars: 1_000; xalign: 0.5; width-request: -9223372036854775808; }");
+
+    assert!(tree.is_ok());
+    assert_eq!(tree.unwrap().get_xml(), String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><interface><object class=\"GtkLabel\"><property name=\"margin-top\">31</property><property name=\"margin-bottom\">-15</property><property name=\"margin-start\">5</property><property name=\"max-width-chars\">1000</property><property name=\"xalign\">0.5</property><property name=\"width-request\">-9223372036854775808</property></object></interface>"));
+}
+
+#[test]
+fn check_number_literals_error() {
+    let tree = Parser::parse("Gtk.Label { xalign: 4.0.1; }");
+
+    assert!(matches!(tree, Err(ParseError::TokenizeError(TokenizeError::IncorrectNumber { begin: 20, end: 24, .. }))));
+
+    let tree = Parser::parse("Gtk.Label { margin-top: 1-2; }");
+
+    assert!(matches!(tree, Err(ParseError::TokenizeError(TokenizeError::IncorrectNumber { begin: 24, end: 26, .. }))));
+
+    let tree = Parser::parse("Gtk.Label { margin-top: 0x; }");
+
+    assert!(matches!(tree, Err(ParseError::TokenizeError(TokenizeError::IncorrectNumber { begin: 24, end: 25, .. }))));
+    let tree = Parser::parse("Gtk.Label { margin-top: 9223372036854775808; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { offset: 24, .. })));
+
+    let tree = Parser::parse("Gtk.Label { margin-top: 0x1_0000_0000_0000_0000; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { offset: 24, .. })));
+
+    let tree = Parser::parse("Gtk.Label { label: expr $f(-9_223_372_036_854_775_809) as <string>; }");
+
+    assert!(matches!(tree, Err(ParseError::IncorrectPropertyDefinition { offset: 27, .. })));
 }
@@ -1,41 +1,35 @@
 use crate::parser::prelude::*;
 
-// As for now we don't check it
-/*#[test]
+#[test]
 fn check_allowed_chars_tokenizing() {
     assert_eq!(Tokenizer::parse("hi;"), Ok(vec![
-        Token::Other { begin: 0, end: 2, value: String::from("hi;") }
+        Token::Identifier { begin: 0, end: 1, value: String::from("hi") },
+        Token::Punctuation { begin: 2, end: 2, value: Punctuation::Semicolon }
     ]));
 
-    if let Err(TokenizeError::IncorrectChar { .. }) = Tokenizer::parse(";hi") {
-        assert!(true);
-    }
-    
-    else {
-        assert!(false);
-    }
-}*/
+    assert!(matches!(Tokenizer::parse("hi@"), Err(TokenizeError::IncorrectChar { offset: 2, .. })));
+}
 
 #[test]
-fn check_other_tokenizing() {
+fn check_identifier_tokenizing() {
     assert_eq!(Tokenizer::parse("Hello World"), Ok(vec![
-        Token::Other { begin: 0, end: 4, value: String::from("Hello") },
-        Token::Other { begin: 6, end: 10, value: String::from("World") }
+        Token::Identifier { begin: 0, end: 4, value: String::from("Hello") },
+        Token::Identifier { begin: 6, end: 10, value: String::from("World") }
     ]));
 
     assert_eq!(Tokenizer::parse("   "), Ok(vec![]));
     assert_eq!(Tokenizer::parse("\n\r\t "), Ok(vec![]));
 
     assert_eq!(Tokenizer::parse("  Hi\r\t"), Ok(vec![
-        Token::Other { begin: 2, end: 3, value: String::from("Hi") }
+        Token::Identifier { begin: 2, end: 3, value: String::from("Hi") }
     ]));
 }
 
 #[test]
 fn check_string_tokenizing() {
     assert_eq!(Tokenizer::parse("  Hi\r\t\n\"Hello, World!\"\n"), Ok(vec![
-        Token::Other { begin: 2, end: 3, value: String::from("Hi") },
-        Token::Text { begin: 7, end: 21, value: String::from("Hello, World!") }
+        Token::Identifier { begin: 2, end: 3, value: String::from("Hi") },
+        Token::Text { begin: 7, end: 21, value: String::from("Hello, World!"), raw: String::from("\"Hello, World!\"") }
     ]));
 }
 
@@ -67,9 +61,9 @@ fn check_brackets_tokenizing() {
 
     assert_eq!(Tokenizer::parse("(a \"b\" c)"), Ok(vec![
         Token::Parentheses { begin: 0, end: 8, tokens: vec![
-            Token::Other { begin: 1, end: 1, value: String::from("a") },
-            Token::Text { begin: 3, end: 5, value: String::from("b") },
-            Token::Other { begin: 7, end: 7, value: String::from("c") }
+            Token::Identifier { begin: 1, end: 1, value: String::from("a") },
+            Token::Text { begin: 3, end: 5, value: String::from("b"), raw: String::from("\"b\"") },
+            Token::Identifier { begin: 7, end: 7, value: String::from("c") }
         ] }
     ]));
 
@@ -77,7 +71,7 @@ fn check_brackets_tokenizing() {
         Token::Parentheses { begin: 1, end: 9, tokens: vec![
             Token::SquareBrackets { begin: 3, end: 4, tokens: vec![] },
             Token::CurlyBrackets { begin: 5, end: 8, tokens: vec![
-                Token::Other { begin: 6, end: 6, value: String::from("a") }
+                Token::Identifier { begin: 6, end: 6, value: String::from("a") }
             ] }
         ] }
     ]));
@@ -91,56 +85,154 @@ fn check_brackets_tokenizing() {
     ]));
 
     assert_eq!(Tokenizer::parse("\n  event => {\n      print!(\"sus\");\n  }\n\n"), Ok(vec![
-        Token::Other { begin: 3, end: 7, value: String::from("event") },
-        Token::Other { begin: 9, end: 10, value: String::from("=>") },
-        Token::CurlyBrackets { begin: 12, end: 37, tokens: vec![
-            Token::Other { begin: 20, end: 25, value: String::from("print!") },
-            Token::Parentheses { begin: 26, end: 32, tokens: vec![
-                Token::Text { begin: 27, end: 31, value: String::from("sus") }
-            ] },
-            Token::Other { begin: 33, end: 33, value: String::from(";") }
-        ] }
+        Token::Identifier { begin: 3, end: 7, value: String::from("event") },
+        Token::Punctuation { begin: 9, end: 10, value: Punctuation::Arrow },
+        Token::CurlyBrackets { begin: 12, end: 37, tokens: vec![] }
+    ]));
+
+    assert_eq!(Tokenizer::parse("e => { print(\"\\x41\"); }"), Ok(vec![
+        Token::Identifier { begin: 0, end: 0, value: String::from("e") },
+        Token::Punctuation { begin: 2, end: 3, value: Punctuation::Arrow },
+        Token::CurlyBrackets { begin: 5, end: 22, tokens: vec![] }
     ]));
 }
 
-// As for now we don't use this error
-/*#[test]
+#[test]
+fn check_punctuation_tokenizing() {
+    assert_eq!(Tokenizer::parse("a::b => c; $d<e>|f, 1.5 -2 g.h i=j"), Ok(vec![
+        Token::Identifier { begin: 0, end: 0, value: String::from("a") },
+        Token::Punctuation { begin: 1, end: 2, value: Punctuation::DoubleColon },
+        Token::Identifier { begin: 3, end: 3, value: String::from("b") },
+        Token::Punctuation { begin: 5, end: 6, value: Punctuation::Arrow },
+        Token::Identifier { begin: 8, end: 8, value: String::from("c") },
+        Token::Punctuation { begin: 9, end: 9, value: Punctuation::Semicolon },
+        Token::Punctuation { begin: 11, end: 11, value: Punctuation::Dollar },
+        Token::Identifier { begin: 12, end: 12, value: String::from("d") },
+        Token::Punctuation { begin: 13, end: 13, value: Punctuation::LessThan },
+        Token::Identifier { begin: 14, end: 14, value: String::from("e") },
+        Token::Punctuation { begin: 15, end: 15, value: Punctuation::GreaterThan },
+        Token::Punctuation { begin: 16, end: 16, value: Punctuation::Pipe },
+        Token::Identifier { begin: 17, end: 17, value: String::from("f") },
+        Token::Punctuation { begin: 18, end: 18, value: Punctuation::Comma },
+        Token::Number { begin: 20, end: 22, value: String::from("1.5") },
+        Token::Number { begin: 24, end: 25, value: String::from("-2") },
+        Token::Identifier { begin: 27, end: 27, value: String::from("g") },
+        Token::Punctuation { begin: 28, end: 28, value: Punctuation::Dot },
+        Token::Identifier { begin: 29, end: 29, value: String::from("h") },
+        Token::Identifier { begin: 31, end: 31, value: String::from("i") },
+        Token::Punctuation { begin: 32, end: 32, value: Punctuation::Equals },
+        Token::Identifier { begin: 33, end: 33, value: String::from("j") }
+    ]));
+}
+
+#[test]
 fn check_incorrect_char_error_tokenizing() {
-    if let Err(TokenizeError::IncorrectChar { .. }) = Tokenizer::parse(")")   { assert!(true); } else { assert!(false); }
-    if let Err(TokenizeError::IncorrectChar { .. }) = Tokenizer::parse("$Hi") { assert!(true); } else { assert!(false); }
-}*/
+    assert!(matches!(Tokenizer::parse(")"), Err(TokenizeError::IncorrectChar { offset: 0, .. })));
+    assert!(matches!(Tokenizer::parse("#Hi"), Err(TokenizeError::IncorrectChar { offset: 0, .. })));
+    assert!(matches!(Tokenizer::parse("{ a ~ b }"), Err(TokenizeError::IncorrectChar { offset: 4, .. })));
+}
+
+#[test]
+fn check_incorrect_number_error_tokenizing() {
+    assert!(matches!(Tokenizer::parse("4.0.1"), Err(TokenizeError::IncorrectNumber { begin: 0, end: 4, .. })));
+    assert!(matches!(Tokenizer::parse("a: 1-2;"), Err(TokenizeError::IncorrectNumber { begin: 3, end: 5, .. })));
+    assert!(matches!(Tokenizer::parse("[0xZ]"), Err(TokenizeError::IncorrectNumber { begin: 1, end: 3, .. })));
+}
 
 #[test]
 fn check_incorrect_string_error_tokenizing() {
-    if let Err(TokenizeError::IncorrectString { .. }) = Tokenizer::parse("Hi \"Example string") { assert!(true); } else { assert!(false); }
-    if let Err(TokenizeError::IncorrectString { .. }) = Tokenizer::parse("\"")                  { assert!(true); } else { assert!(false); }
+    assert!(matches!(Tokenizer::parse("Hi \"Example string"), Err(TokenizeError::IncorrectString { .. })));
+    assert!(matches!(Tokenizer::parse("\""), Err(TokenizeError::IncorrectString { .. })));
 }
 
 #[test]
 fn check_incorrect_brackets_error_tokenizing() {
-    if let Err(TokenizeError::IncorrectBrackets { .. }) = Tokenizer::parse("Hi\r[(){]")  { assert!(true); } else { assert!(false); }
-    if let Err(TokenizeError::IncorrectBrackets { .. }) = Tokenizer::parse("[)")         { assert!(true); } else { assert!(false); }
-    if let Err(TokenizeError::IncorrectBrackets { .. }) = Tokenizer::parse("Incorrect[") { assert!(true); } else { assert!(false); }
+    assert!(matches!(Tokenizer::parse("Hi\r[(){]"), Err(TokenizeError::IncorrectBrackets { .. })));
+    assert!(matches!(Tokenizer::parse("[)"), Err(TokenizeError::IncorrectBrackets { .. })));
+    assert!(matches!(Tokenizer::parse("Incorrect["), Err(TokenizeError::IncorrectBrackets { .. })));
 }
 
 #[test]
 fn check_complex_tokenizing() {
     assert_eq!(Tokenizer::parse("use Gtk 4.0\nuse Adw 1\n\nAdw.ApplicationWindow window {\n\tdefault-width: 900\n\tdefault-height: 600\n}\n"), Ok(vec![
-        Token::Other { begin: 0, end: 2, value: String::from("use") },
-        Token::Other { begin: 4, end: 6, value: String::from("Gtk") },
-        Token::Other { begin: 8, end: 10, value: String::from("4.0") },
-
-        Token::Other { begin: 12, end: 14, value: String::from("use") },
-        Token::Other { begin: 16, end: 18, value: String::from("Adw") },
-        Token::Other { begin: 20, end: 20, value: String::from("1") },
-
-        Token::Other { begin: 23, end: 43, value: String::from("Adw.ApplicationWindow") },
-        Token::Other { begin: 45, end: 50, value: String::from("window") },
+        Token::Identifier { begin: 0, end: 2, value: String::from("use") },
+        Token::Identifier { begin: 4, end: 6, value: String::from("Gtk") },
+        Token::Number { begin: 8, end: 10, value: String::from("4.0") },
+
+        Token::Identifier { begin: 12, end: 14, value: String::from("use") },
+        Token::Identifier { begin: 16, end: 18, value: String::from("Adw") },
+        Token::Number { begin: 20, end: 20, value: String::from("1") },
+
+        Token::Identifier { begin: 23, end: 25, value: String::from("Adw") },
+        Token::Punctuation { begin: 26, end: 26, value: Punctuation::Dot },
+        Token::Identifier { begin: 27, end: 43, value: String::from("ApplicationWindow") },
+        Token::Identifier { begin: 45, end: 50, value: String::from("window") },
         Token::CurlyBrackets { begin: 52, end: 95, tokens: vec![
-            Token::Other { begin: 55, end: 68, value: String::from("default-width:") },
-            Token::Other { begin: 70, end: 72, value: String::from("900") },
-            Token::Other { begin: 75, end: 89, value: String::from("default-height:") },
-            Token::Other { begin: 91, end: 93, value: String::from("600") },
+            Token::Identifier { begin: 55, end: 67, value: String::from("default-width") },
+            Token::Punctuation { begin: 68, end: 68, value: Punctuation::Colon },
+            Token::Number { begin: 70, end: 72, value: String::from("900") },
+            Token::Identifier { begin: 75, end: 88, value: String::from("default-height") },
+            Token::Punctuation { begin: 89, end: 89, value: Punctuation::Colon },
+            Token::Number { begin: 91, end: 93, value: String::from("600") },
         ] },
     ]));
 }
+
+#[test]
+fn check_comments_tokenizing() {
+    assert_eq!(Tokenizer::parse("a // comment (\nb /* multi\nline */ c/**/d"), Ok(vec![
+        Token::Identifier { begin: 0, end: 0, value: String::from("a") },
+        Token::Identifier { begin: 15, end: 15, value: String::from("b") },
+        Token::Identifier { begin: 34, end: 34, value: String::from("c") },
+        Token::Identifier { begin: 39, end: 39, value: String::from("d") }
+    ]));
+
+    assert_eq!(Tokenizer::parse("{ a // } \n \"/*\" }"), Ok(vec![
+        Token::CurlyBrackets { begin: 0, end: 16, tokens: vec![
+            Token::Identifier { begin: 2, end: 2, value: String::from("a") },
+            Token::Text { begin: 11, end: 14, value: String::from("/*"), raw: String::from("\"/*\"") }
+        ] }
+    ]));
+
+    assert_eq!(Tokenizer::parse_with_trivia("a /* b */ [c // d\n]"), Ok(vec![
+        Token::Identifier { begin: 0, end: 0, value: String::from("a") },
+        Token::Comment { begin: 2, end: 8, value: String::from("/* b */") },
+        Token::SquareBrackets { begin: 10, end: 18, tokens: vec![
+            Token::Identifier { begin: 11, end: 11, value: String::from("c") },
+            Token::Comment { begin: 13, end: 16, value: String::from("// d") }
+        ] }
+    ]));
+}
+
+#[test]
+fn check_incorrect_comment_error_tokenizing() {
+    assert!(matches!(Tokenizer::parse("a /* b"), Err(TokenizeError::IncorrectComment { begin: 2, end: 5, .. })));
+    assert!(matches!(Tokenizer::parse("/*/"), Err(TokenizeError::IncorrectComment { .. })));
+    assert!(matches!(Tokenizer::parse("{ /* } */"), Err(TokenizeError::IncorrectBrackets { .. })));
+}
+
+#[test]
+fn check_string_escapes_tokenizing() {
+    assert_eq!(Tokenizer::parse("\"Say \\\"hi\\\"\\n\\t\\\\ \\u00e9\""), Ok(vec![
+        Token::Text { begin: 0, end: 24, value: String::from("Say \"hi\"\n\t\\ \u{e9}"), raw: String::from("\"Say \\\"hi\\\"\\n\\t\\\\ \\u00e9\"") }
+    ]));
+
+    assert_eq!(Tokenizer::parse("'It\\'s \"quoted\"' (\")\")"), Ok(vec![
+        Token::Text { begin: 0, end: 15, value: String::from("It's \"quoted\""), raw: String::from("'It\\'s \"quoted\"'") },
+        Token::Parentheses { begin: 17, end: 21, tokens: vec![
+            Token::Text { begin: 18, end: 20, value: String::from(")"), raw: String::from("\")\"") }
+        ] }
+    ]));
+
+    assert_eq!(Tokenizer::parse("\"multi\nline \"\n  'strings'"), Ok(vec![
+        Token::Text { begin: 0, end: 24, value: String::from("multi\nline strings"), raw: String::from("\"multi\nline \"\n  'strings'") }
+    ]));
+}
+
+#[test]
+fn check_incorrect_string_escapes_error_tokenizing() {
+    assert!(matches!(Tokenizer::parse("a \"b \\d\""), Err(TokenizeError::IncorrectString { begin: 5, end: 6, .. })));
+    assert!(matches!(Tokenizer::parse("(\"\\u12\")"), Err(TokenizeError::IncorrectString { begin: 2, end: 5, .. })));
+    assert!(matches!(Tokenizer::parse("label: '\\u';"), Err(TokenizeError::IncorrectString { begin: 8, end: 9, .. })));
+    assert!(matches!(Tokenizer::parse("'unterminated\\'"), Err(TokenizeError::IncorrectString { .. })));
+}